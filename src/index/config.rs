@@ -0,0 +1,201 @@
+//! Describe the analyzers of an [`Index`][index] and the mappings of its fields in JSON.
+//!
+//! The configuration is an object with the following optional keys:
+//! - `analyzers`: the [analyzers][analyzer] to register, keyed by name, in the JSON representation
+//!   described in the `analyzer` module;
+//! - `fields`: the mappings of the fields, keyed by field, to the name of the `analyzer` of its
+//!   values, and optionally to the name of the `search_analyzer` of the query terms, which must
+//!   be a normalizer.
+//!
+//! Only JSON is parsed by [`Index::configure`][configure]. The [`IndexConfig`] type is
+//! deserializable, so that a configuration in another format, e.g., TOML with the `toml` crate,
+//! can be applied with [`Index::apply_config`][apply_config]:
+//!
+//! ```toml
+//! [analyzers.tag]
+//! tokenizer = "keyword"
+//! filters = ["lowercase"]
+//!
+//! [fields.status]
+//! analyzer = "tag"
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::index::Index;
+//! use ::iryfful::index::document::Document;
+//! use ::iryfful::search::IndexSearcher;
+//! use ::iryfful::search::SearchHit;
+//! use ::iryfful::search::query::Query;
+//! use ::iryfful::search::query::term_query::TermQuery;
+//!
+//! let mut index: Index = Default::default();
+//! index.configure(r#"{
+//!     "analyzers": {
+//!         "english": {"tokenizer": "standard", "filters": ["lowercase", {"stemmer": "porter"}]},
+//!         "tag": {"tokenizer": "keyword", "filters": ["lowercase"]}
+//!     },
+//!     "fields": {
+//!         "title": {"analyzer": "english"},
+//!         "body": {"analyzer": "english"},
+//!         "status": {"analyzer": "tag"}
+//!     }
+//! }"#).unwrap();
+//!
+//! let mut doc: Document = Default::default();
+//! doc.add_field("title", "Indexing documents");
+//! doc.add_field("status", "In Progress");
+//! index.add_doc(&doc).unwrap();
+//!
+//! let index_search = &IndexSearcher::new(&index);
+//! let tq = TermQuery::new("status", "IN PROGRESS");
+//! let mut iter = tq.execute(index_search);
+//!
+//! assert_eq!(iter.next(), Some(SearchHit::new(0)));
+//! assert_eq!(iter.next(), None);
+//! ```
+//!
+//! [index]: ../struct.Index.html
+//! [configure]: ../struct.Index.html#method.configure
+//! [apply_config]: ../struct.Index.html#method.apply_config
+//! [analyzer]: ../../tokenizer/analyzer/index.html
+use super::IndexingResult;
+use super::error::IndexingError;
+use serde_json;
+use serde_path_to_error;
+use std::collections::BTreeMap;
+use tokenizer::analyzer::AnalyzerDsl;
+use tokenizer::error::TokenizerError;
+
+/// The serializable representation of the analyzers and mappings of an index.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IndexConfig {
+    #[serde(default)]
+    pub analyzers: BTreeMap<String, AnalyzerDsl>,
+    #[serde(default)]
+    pub fields: BTreeMap<String, FieldConfig>,
+}
+
+/// The serializable representation of the mapping of a field.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FieldConfig {
+    pub analyzer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search_analyzer: Option<String>,
+}
+
+/// Parses the JSON representation of an index configuration.
+///
+/// # Errors
+///
+/// An [`IndexingError::InvalidConfig`] error is returned if the JSON document is malformed or
+/// does not describe a configuration.
+pub fn parse_json(json: &str) -> IndexingResult<IndexConfig> {
+    let deserializer = &mut serde_json::Deserializer::from_str(json);
+    serde_path_to_error::deserialize(deserializer).map_err(|err| IndexingError::InvalidConfig {
+        path: path_of(err.path()),
+        reason: err.inner().to_string(),
+    })
+}
+
+/// Returns the given path as reported in errors, i.e., empty for the root rather than `.`.
+fn path_of(path: &serde_path_to_error::Path) -> String {
+    if path.iter().next().is_none() {
+        String::new()
+    } else {
+        path.to_string()
+    }
+}
+
+/// Converts the error of an invalid analyzer into an invalid configuration error.
+pub fn invalid(err: TokenizerError) -> IndexingError {
+    match err {
+        TokenizerError::InvalidAnalyzer { path, reason } => {
+            IndexingError::InvalidConfig { path, reason }
+        }
+        err => IndexingError::InvalidConfig {
+            path: String::new(),
+            reason: err.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use index::Index;
+
+    fn assert_invalid(json: &str, expected_path: &str) {
+        let mut index: Index = Default::default();
+        match index.configure(json) {
+            Err(IndexingError::InvalidConfig { path, .. }) => assert_eq!(path, expected_path),
+            res => panic!("expected an error at {}, got {:?}", expected_path, res),
+        }
+    }
+
+    #[test]
+    fn test_invalid_configs() {
+        assert_invalid(r#""config""#, "");
+        assert_invalid(r#"{"mappings": {}}"#, "mappings");
+        assert_invalid(r#"{"fields": {"title": {}}}"#, "fields.title");
+        assert_invalid(
+            r#"{"fields": {"title": {"analyzer": "std", "boost": 2}}}"#,
+            "fields.title.boost",
+        );
+        assert_invalid(
+            r#"{"analyzers": {"std": {"tokenizer": "standard", "filters": ["uppercase"]}}}"#,
+            "analyzers.std.filters[0]",
+        );
+        assert_invalid(
+            r#"{"analyzers": {"grams": {"tokenizer": {"ngram": {"min_gram": 3, "max_gram": 2}}}}}"#,
+            "analyzers.grams.tokenizer.ngram",
+        );
+    }
+
+    #[test]
+    fn test_missing_analyzer() {
+        let mut index: Index = Default::default();
+        match index.configure(r#"{"fields": {"title": {"analyzer": "english"}}}"#) {
+            Err(IndexingError::MissingAnalyzer { name }) => assert_eq!(name, "english"),
+            res => panic!("expected a missing analyzer, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn test_failed_config_leaves_index_unchanged() {
+        let mut index: Index = Default::default();
+        let analyzers = r#""std": {"tokenizer": "standard"}, "tag": {"tokenizer": "keyword"}"#;
+
+        // an invalid analyzer after valid ones
+        let json = format!(
+            r#"{{"analyzers": {{{}, "upper": {{"filters": ["uppercase"]}}}}}}"#,
+            analyzers
+        );
+        match index.configure(&json) {
+            Err(IndexingError::InvalidConfig { path, .. }) => {
+                assert_eq!(path, "analyzers.upper.filters[0]")
+            }
+            res => panic!("expected an invalid analyzer, got {:?}", res),
+        }
+        // an invalid search analyzer after a valid mapping
+        let json = |search_analyzer: &str| {
+            format!(
+                r#"{{"analyzers": {{{}}}, "fields": {{
+                    "body": {{"analyzer": "std"}},
+                    "title": {{"analyzer": "std", "search_analyzer": "{}"}}
+                }}}}"#,
+                analyzers, search_analyzer
+            )
+        };
+        match index.configure(&json("std")) {
+            Err(IndexingError::InvalidSearchAnalyzer { name }) => assert_eq!(name, "std"),
+            res => panic!("expected an invalid search analyzer, got {:?}", res),
+        }
+
+        // neither the analyzers nor the mapping of body were kept
+        index.configure(&json("tag")).unwrap();
+    }
+}
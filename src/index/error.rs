@@ -10,4 +10,42 @@ pub enum IndexingError {
     /// The mapping for a field does not exist.
     #[fail(display = "missing mapping for field: {}", field)]
     MissingFieldMapping { field: String },
+
+    /// An analyzer with the same name was already registered.
+    #[fail(display = "analyzer already exists: {}", name)]
+    AnalyzerAlreadyExists { name: String },
+
+    /// The offsets of a field can only be stored before any document is indexed, so that all the
+    /// tokens of its posting lists have offsets.
+    #[fail(display = "documents are already indexed, cannot store the offsets of field: {}", field)]
+    DocumentsAlreadyIndexed { field: String },
+
+    /// The query terms are looked up as a single normalized term, so only a normalizer can be set
+    /// as the search analyzer of a field.
+    #[fail(display = "search analyzer is not a normalizer: {}", name)]
+    InvalidSearchAnalyzer { name: String },
+
+    /// No analyzer is registered with that name.
+    #[fail(display = "missing analyzer: {}", name)]
+    MissingAnalyzer { name: String },
+
+    /// A document has a term longer than the maximum term length of the index.
+    #[fail(
+        display = "term of {} characters in field {} of document {} exceeds the maximum length \
+                   of {}: {}...",
+        length, field, doc_id, max_length, prefix
+    )]
+    TermTooLong {
+        /// The ID the rejected document would have had, i.e., the number of indexed documents
+        doc_id: u32,
+        field: String,
+        /// The start of the term, up to the maximum length
+        prefix: String,
+        length: usize,
+        max_length: usize,
+    },
+
+    /// The index configuration is not valid. The path points to the offending element.
+    #[fail(display = "invalid configuration at {}: {}", path, reason)]
+    InvalidConfig { path: String, reason: String },
 }
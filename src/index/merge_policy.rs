@@ -0,0 +1,300 @@
+//! Policies selecting the segments of an index to merge.
+//!
+//! Each flush of the [`IndexWriter`][writer] adds a small [`Segment`][segment] to the index, and
+//! searching over many segments is slower than over a few large ones. A [`MergePolicy`] selects
+//! runs of consecutive segments to merge, trading the cost of rewriting them for fewer segments:
+//! - the [`TieredMergePolicy`] allows a number of segments per tier of size, and merges the
+//!   segments of most similar sizes once there are too many;
+//! - the [`LogSizeMergePolicy`] merges a given number of segments of the same level, the level of
+//!   a segment being the logarithm of its size.
+//!
+//! Only consecutive segments are merged so that the documents keep their IDs.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::index::merge_policy::{LogSizeMergePolicy, MergePolicy};
+//!
+//! let mut policy = LogSizeMergePolicy::new();
+//! policy.set_merge_factor(3);
+//! policy.set_min_merge_docs(10);
+//!
+//! // the first three segments are of level 0, the fourth of level 1
+//! let merges = policy.find_merges(&[5, 10, 8, 100, 4]);
+//! assert_eq!(merges, vec![0..3]);
+//! ```
+//!
+//! [writer]: ../writer/struct.IndexWriter.html
+//! [segment]: ../segment/struct.Segment.html
+use std::ops::Range;
+
+/// The `MergePolicy` type selects the segments to merge.
+pub trait MergePolicy {
+    /// Returns the ranges of consecutive segments to merge, given the number of documents of
+    /// each segment, from the oldest to the newest.
+    ///
+    /// The ranges must not overlap, and each must span at least 2 segments.
+    fn find_merges(&self, segments: &[u32]) -> Vec<Range<usize>>;
+}
+
+/// A [`MergePolicy`] allowing a number of segments per tier of size.
+///
+/// The first tier is made of segments up to the floor size, and each following tier of
+/// segments `max_merge_at_once` times larger. Once the index has more segments than allowed by
+/// its total size, the run of consecutive segments of most similar sizes is merged.
+#[derive(Debug)]
+pub struct TieredMergePolicy {
+    segments_per_tier: usize,
+    max_merge_at_once: usize,
+    floor_segment_docs: u32,
+    max_merged_segment_docs: u32,
+}
+
+impl Default for TieredMergePolicy {
+    fn default() -> TieredMergePolicy {
+        TieredMergePolicy::new()
+    }
+}
+
+impl TieredMergePolicy {
+    /// Creates a new tiered merge policy.
+    ///
+    /// By default, 10 segments are allowed per tier, at most 10 segments are merged at once, the
+    /// segments of less than 1000 documents are considered of that size, and merged segments
+    /// have at most 5 million documents.
+    pub fn new() -> TieredMergePolicy {
+        TieredMergePolicy {
+            segments_per_tier: 10,
+            max_merge_at_once: 10,
+            floor_segment_docs: 1000,
+            max_merged_segment_docs: 5_000_000,
+        }
+    }
+
+    /// Sets the number of segments allowed per tier.
+    pub fn set_segments_per_tier(&mut self, segments_per_tier: usize) {
+        self.segments_per_tier = segments_per_tier.max(2);
+    }
+
+    /// Sets the maximum number of segments merged at once.
+    pub fn set_max_merge_at_once(&mut self, max_merge_at_once: usize) {
+        self.max_merge_at_once = max_merge_at_once.max(2);
+    }
+
+    /// Sets the number of documents under which segments are considered of that size, so that
+    /// tiny segments are merged together.
+    pub fn set_floor_segment_docs(&mut self, floor_segment_docs: u32) {
+        self.floor_segment_docs = floor_segment_docs.max(1);
+    }
+
+    /// Sets the maximum number of documents of a merged segment. Segments larger than half of it
+    /// are not merged anymore.
+    pub fn set_max_merged_segment_docs(&mut self, max_merged_segment_docs: u32) {
+        self.max_merged_segment_docs = max_merged_segment_docs;
+    }
+
+    /// Returns the number of segments allowed for an index of the given number of documents.
+    fn allowed_segments(&self, num_docs: u64) -> usize {
+        let mut allowed = 0;
+        let mut remaining = num_docs;
+        let mut tier_size = u64::from(self.floor_segment_docs);
+        loop {
+            let segments = remaining.div_ceil(tier_size);
+            if segments <= self.segments_per_tier as u64 {
+                allowed += segments as usize;
+                break;
+            }
+            allowed += self.segments_per_tier;
+            remaining -= self.segments_per_tier as u64 * tier_size;
+            tier_size *= self.max_merge_at_once as u64;
+        }
+        allowed.max(self.segments_per_tier)
+    }
+
+    /// Returns the score of merging the given segments, the lower the better: merging segments
+    /// of similar sizes is favoured, since it avoids rewriting a large segment over and over.
+    fn score(&self, segments: &[u32]) -> f64 {
+        let floored = |size: u32| f64::from(size.max(self.floor_segment_docs));
+        let total: f64 = segments.iter().map(|&size| floored(size)).sum();
+        let largest = segments.iter().map(|&size| floored(size)).fold(0.0, f64::max);
+        // the skew, slightly favouring smaller merges
+        largest / total * total.powf(0.05)
+    }
+}
+
+impl MergePolicy for TieredMergePolicy {
+    fn find_merges(&self, segments: &[u32]) -> Vec<Range<usize>> {
+        let max_segment_docs = self.max_merged_segment_docs / 2;
+        let mut eligible: Vec<bool> = segments
+            .iter()
+            .map(|&size| size <= max_segment_docs)
+            .collect();
+        let num_docs = segments
+            .iter()
+            .zip(eligible.iter())
+            .filter(|&(_, &eligible)| eligible)
+            .map(|(&size, _)| u64::from(size))
+            .sum();
+        let allowed = self.allowed_segments(num_docs);
+        let mut count = eligible.iter().filter(|&&eligible| eligible).count();
+
+        let mut merges = Vec::new();
+        while count > allowed {
+            let mut best: Option<(f64, Range<usize>)> = None;
+            for start in 0..segments.len() {
+                let mut total = 0;
+                for end in (start + 1)..segments.len().min(start + self.max_merge_at_once) + 1 {
+                    if !eligible[end - 1] {
+                        break;
+                    }
+                    total += u64::from(segments[end - 1]);
+                    if total > u64::from(self.max_merged_segment_docs) {
+                        break;
+                    }
+                    if end - start < 2 {
+                        continue;
+                    }
+                    let score = self.score(&segments[start..end]);
+                    let better = match best {
+                        Some((best_score, _)) => score < best_score,
+                        None => true,
+                    };
+                    if better {
+                        best = Some((score, start..end));
+                    }
+                }
+            }
+            match best {
+                Some((_, range)) => {
+                    for eligible in &mut eligible[range.clone()] {
+                        *eligible = false;
+                    }
+                    count -= range.len() - 1;
+                    merges.push(range);
+                }
+                None => break,
+            }
+        }
+        merges.sort_by_key(|range| range.start);
+        merges
+    }
+}
+
+/// A [`MergePolicy`] merging segments of the same level.
+///
+/// The level of a segment is the logarithm in base `merge_factor` of its size in units of
+/// `min_merge_docs`. Once `merge_factor` consecutive segments are of the same level, they are
+/// merged into a segment of the next level.
+#[derive(Debug)]
+pub struct LogSizeMergePolicy {
+    merge_factor: usize,
+    min_merge_docs: u32,
+    max_merge_docs: u32,
+}
+
+impl Default for LogSizeMergePolicy {
+    fn default() -> LogSizeMergePolicy {
+        LogSizeMergePolicy::new()
+    }
+}
+
+impl LogSizeMergePolicy {
+    /// Creates a new log-size merge policy.
+    ///
+    /// By default, 10 segments of the same level are merged, the segments of less than 1000
+    /// documents are of the lowest level, and segments of any size are merged.
+    pub fn new() -> LogSizeMergePolicy {
+        LogSizeMergePolicy {
+            merge_factor: 10,
+            min_merge_docs: 1000,
+            max_merge_docs: u32::MAX,
+        }
+    }
+
+    /// Sets the number of segments of the same level to merge.
+    pub fn set_merge_factor(&mut self, merge_factor: usize) {
+        self.merge_factor = merge_factor.max(2);
+    }
+
+    /// Sets the number of documents under which segments are of the lowest level.
+    pub fn set_min_merge_docs(&mut self, min_merge_docs: u32) {
+        self.min_merge_docs = min_merge_docs.max(1);
+    }
+
+    /// Sets the number of documents over which segments are not merged anymore.
+    pub fn set_max_merge_docs(&mut self, max_merge_docs: u32) {
+        self.max_merge_docs = max_merge_docs;
+    }
+
+    fn level(&self, size: u32) -> u32 {
+        let mut level = 0;
+        let mut units = u64::from(size) / u64::from(self.min_merge_docs);
+        while units >= self.merge_factor as u64 {
+            units /= self.merge_factor as u64;
+            level += 1;
+        }
+        level
+    }
+}
+
+impl MergePolicy for LogSizeMergePolicy {
+    fn find_merges(&self, segments: &[u32]) -> Vec<Range<usize>> {
+        let mut merges = Vec::new();
+        let mut start = 0;
+        while start + self.merge_factor <= segments.len() {
+            let end = start + self.merge_factor;
+            let level = self.level(segments[start]);
+            let mergeable = segments[start..end]
+                .iter()
+                .all(|&size| size <= self.max_merge_docs && self.level(size) == level);
+            if mergeable {
+                merges.push(start..end);
+                start = end;
+            } else {
+                start += 1;
+            }
+        }
+        merges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tiered() {
+        let mut policy = TieredMergePolicy::new();
+        policy.set_segments_per_tier(3);
+        policy.set_max_merge_at_once(3);
+        policy.set_floor_segment_docs(10);
+
+        // within the allowed number of segments
+        assert!(policy.find_merges(&[]).is_empty());
+        assert!(policy.find_merges(&[10, 10, 10]).is_empty());
+        assert!(policy.find_merges(&[200, 100, 10, 10, 10, 10, 10, 10, 10]).is_empty());
+
+        // the small segments are merged together rather than with the large ones
+        let segments = [200, 100, 10, 10, 10, 10, 10, 10, 10, 10];
+        assert_eq!(policy.find_merges(&segments), vec![2..5]);
+
+        // the large segments are left out of merges
+        policy.set_max_merged_segment_docs(150);
+        assert_eq!(policy.find_merges(&segments), vec![2..5, 5..8]);
+    }
+
+    #[test]
+    fn test_log_size() {
+        let mut policy = LogSizeMergePolicy::new();
+        policy.set_merge_factor(3);
+        policy.set_min_merge_docs(10);
+
+        assert!(policy.find_merges(&[5, 10]).is_empty());
+        assert_eq!(policy.find_merges(&[5, 10, 8, 1, 2, 3]), vec![0..3, 3..6]);
+        assert_eq!(policy.find_merges(&[30, 5, 10, 8]), vec![1..4]);
+        assert_eq!(policy.find_merges(&[30, 40, 50, 9]), vec![0..3]);
+
+        policy.set_max_merge_docs(35);
+        assert!(policy.find_merges(&[30, 40, 20, 9]).is_empty());
+    }
+}
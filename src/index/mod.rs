@@ -1,38 +1,160 @@
 //! Indexing logic of documents.
 //!
 //! The [`Index`] type provides an API for adding documents to an index and interacting with it.
-use index::posting_lists::Posting;
+//!
+//! The documents are stored in immutable [`segment`]s written by an [`writer::IndexWriter`], and
+//! an [`reader::IndexReader`] opened on the index can be searched while documents are added.
+use index::posting_lists::SegmentedPosting;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::collections::hash_map::Entry;
-use tokenizer::Tokenizer;
+use std::sync::Arc;
+use tokenizer::{Token, Tokenizer};
+use tokenizer::analysis::Analysis;
+use tokenizer::analyzer::Analyzer;
+use tokenizer::language::Language;
+use tokenizer::language_detector::LanguageDetector;
 
+pub mod config;
 pub mod document;
 pub mod error;
+pub mod merge_policy;
 pub mod posting_lists;
+pub mod reader;
+pub mod segment;
+pub mod term_vectors;
+pub mod writer;
 
 type IndexingResult<T> = Result<T, error::IndexingError>;
 
+/// A tokenizer shared by the mappings of the index and its readers, possibly across threads.
+type SharedTokenizer<'a> = Arc<Tokenizer + Send + Sync + 'a>;
+
 #[derive(Default)]
 pub struct Index<'a> {
-    doc_id: u32,
-    postings: HashMap<String, posting_lists::PostingImpl>,
-    mappings: HashMap<String, Box<Tokenizer + 'a>>,
+    mappings: Arc<Mappings<'a>>,
+    analyzers: HashMap<String, SharedTokenizer<'a>>,
+    language_detector: Option<LanguageDetector>,
+    max_term_length: Option<usize>,
+    offsets: HashSet<String>,
+    term_vectors: HashSet<String>,
+    writer: writer::IndexWriter,
+}
+
+/// The mappings of the fields, shared by the index with its readers.
+#[derive(Clone, Default)]
+struct Mappings<'a> {
+    fields: HashMap<String, FieldMapping<'a>>,
+}
+
+impl<'a> Mappings<'a> {
+    fn get_tokenizer(&self, field: &str) -> Option<&(Tokenizer + 'a)> {
+        self.fields
+            .get(field)
+            .map(|mapping| &*mapping.index_analyzer as &(Tokenizer + 'a))
+    }
+
+    fn get_search_tokenizer(&self, field: &str) -> Option<&(Tokenizer + 'a)> {
+        self.fields.get(field).map(|mapping| {
+            &**mapping
+                .search_analyzer
+                .as_ref()
+                .unwrap_or(&mapping.index_analyzer) as &(Tokenizer + 'a)
+        })
+    }
+
+    fn normalize<'t>(&self, field: &str, term: &'t str) -> Cow<'t, str> {
+        match self.get_search_tokenizer(field) {
+            Some(tokenizer) if tokenizer.is_normalizer() => Cow::Owned(tokenizer.normalize(term)),
+            _ => Cow::Borrowed(term),
+        }
+    }
+}
+
+/// The analyzers of a field.
+#[derive(Clone)]
+struct FieldMapping<'a> {
+    /// The analyzer of the values of the field in the indexed documents
+    index_analyzer: SharedTokenizer<'a>,
+    /// The analyzer of the query terms, if different
+    search_analyzer: Option<SharedTokenizer<'a>>,
+    /// The analyzers of the values by detected language, if any
+    languages: Option<LanguageRouting<'a>>,
+}
+
+impl<'a> FieldMapping<'a> {
+    /// Returns the analyzer of the given value, with its detected language if the field is
+    /// analyzed per language.
+    fn route(
+        &self,
+        detector: Option<&LanguageDetector>,
+        value: &str,
+    ) -> (&SharedTokenizer<'a>, Option<Language>) {
+        let routing = match self.languages {
+            Some(ref routing) => routing,
+            None => return (&self.index_analyzer, None),
+        };
+        match detector.and_then(|detector| detector.detect(value)) {
+            Some(language) => (
+                routing
+                    .analyzers
+                    .get(&language)
+                    .unwrap_or(&self.index_analyzer),
+                Some(language),
+            ),
+            None => (&self.index_analyzer, None),
+        }
+    }
+}
+
+/// The per-language analysis of a field.
+#[derive(Clone)]
+struct LanguageRouting<'a> {
+    /// The field recording the detected languages
+    field: String,
+    analyzers: HashMap<Language, SharedTokenizer<'a>>,
 }
 
 impl<'a> Index<'a> {
     /// Sets the tokenizer to be used on content of the specified field.
     ///
+    /// The tokenizer is shared with the readers of the index, which may be sent to other threads.
+    ///
     /// # Errors
     ///
     /// An [`error::IndexingError::MappingFieldAlreadyExists`] error is returned if a tokenizer is
-    /// already set for the specified field.
+    /// already set for the specified field, or if it is a language field, see
+    /// [`Index::set_language_analyzers`].
     pub fn set_mapping<T: 'a>(&mut self, field: String, tokenizer: T) -> IndexingResult<()>
     where
-        T: Tokenizer,
+        T: Tokenizer + Send + Sync,
     {
-        match self.mappings.entry(field) {
+        self.set_field_analyzer(field, Arc::new(tokenizer))
+    }
+
+    fn set_field_analyzer(
+        &mut self,
+        field: String,
+        analyzer: SharedTokenizer<'a>,
+    ) -> IndexingResult<()> {
+        // the language fields hold the detected languages, see set_language_analyzers
+        let is_language_field = self.mappings
+            .fields
+            .values()
+            .filter_map(|mapping| mapping.languages.as_ref())
+            .any(|routing| routing.field == field);
+        if is_language_field {
+            return Err(error::IndexingError::MappingFieldAlreadyExists { field });
+        }
+        match Arc::make_mut(&mut self.mappings).fields.entry(field) {
             Entry::Vacant(entry) => {
-                entry.insert(Box::new(tokenizer));
+                entry.insert(FieldMapping {
+                    index_analyzer: analyzer,
+                    search_analyzer: None,
+                    languages: None,
+                });
                 Ok(())
             }
             Entry::Occupied(entry) => Err(error::IndexingError::MappingFieldAlreadyExists {
@@ -41,47 +163,400 @@ impl<'a> Index<'a> {
         }
     }
 
+    /// Registers the tokenizer, e.g., an [`Analyzer`][analyzer], under the given name, so that it
+    /// can be shared by several fields.
+    ///
+    /// # Errors
+    ///
+    /// An [`error::IndexingError::AnalyzerAlreadyExists`] error is returned if an analyzer is
+    /// already registered with that name.
+    ///
+    /// [analyzer]: ../tokenizer/analyzer/struct.Analyzer.html
+    pub fn register_analyzer<T: 'a>(&mut self, name: String, analyzer: T) -> IndexingResult<()>
+    where
+        T: Tokenizer + Send + Sync,
+    {
+        match self.analyzers.entry(name) {
+            Entry::Vacant(entry) => {
+                entry.insert(Arc::new(analyzer));
+                Ok(())
+            }
+            Entry::Occupied(entry) => Err(error::IndexingError::AnalyzerAlreadyExists {
+                name: entry.key().to_string(),
+            }),
+        }
+    }
+
+    fn get_analyzer(&self, name: &str) -> IndexingResult<SharedTokenizer<'a>> {
+        self.analyzers
+            .get(name)
+            .cloned()
+            .ok_or_else(|| error::IndexingError::MissingAnalyzer {
+                name: name.to_string(),
+            })
+    }
+
+    /// Sets the registered analyzer to be used on content of the specified field, and on the
+    /// query terms unless a search analyzer is set.
+    ///
+    /// # Errors
+    ///
+    /// An [`error::IndexingError::MissingAnalyzer`] error is returned if no analyzer is
+    /// registered with that name, and an [`error::IndexingError::MappingFieldAlreadyExists`]
+    /// error if a tokenizer is already set for the specified field or if it is a language field.
+    pub fn set_analyzer(&mut self, field: String, analyzer: &str) -> IndexingResult<()> {
+        let analyzer = self.get_analyzer(analyzer)?;
+        self.set_field_analyzer(field, analyzer)
+    }
+
+    /// Sets the registered analyzer to be used on the query terms of the specified field.
+    ///
+    /// A query term is looked up as a single term, normalized with [`Index::normalize`], so the
+    /// search analyzer must be a normalizer, e.g., a keyword tokenizer with filters. A full-text
+    /// field is queried with the terms produced by its tokenizer.
+    ///
+    /// # Errors
+    ///
+    /// An [`error::IndexingError::MissingAnalyzer`] error is returned if no analyzer is
+    /// registered with that name, an [`error::IndexingError::InvalidSearchAnalyzer`] error if the
+    /// analyzer is not a normalizer, and an [`error::IndexingError::MissingFieldMapping`] error if
+    /// the field has no mapping defined.
+    pub fn set_search_analyzer(&mut self, field: &str, name: &str) -> IndexingResult<()> {
+        let analyzer = self.get_analyzer(name)?;
+        if !analyzer.is_normalizer() {
+            return Err(error::IndexingError::InvalidSearchAnalyzer {
+                name: name.to_string(),
+            });
+        }
+        match Arc::make_mut(&mut self.mappings).fields.get_mut(field) {
+            Some(mapping) => {
+                mapping.search_analyzer = Some(analyzer);
+                Ok(())
+            }
+            None => Err(error::IndexingError::MissingFieldMapping {
+                field: field.to_string(),
+            }),
+        }
+    }
+
+    /// Detects the language of the values of the specified field, to analyze each value with
+    /// the registered analyzer of its language.
+    ///
+    /// The values whose language is not detected or has no analyzer are analyzed with the
+    /// tokenizer of the field. The detected languages of a document are indexed as terms of
+    /// `language_field`, e.g., `english`, so that queries can be filtered on them.
+    ///
+    /// The language field has no mapping, and is queried with the [`Language::name`] of a
+    /// language, e.g., `TermQuery::new("lang", "english")`. It can neither be analyzed nor store
+    /// offsets.
+    ///
+    /// # Errors
+    ///
+    /// An [`error::IndexingError::MissingAnalyzer`] error is returned if one of the analyzers is
+    /// not registered, an [`error::IndexingError::MissingFieldMapping`] error if the field has no
+    /// mapping defined, and an [`error::IndexingError::MappingFieldAlreadyExists`] error if
+    /// `language_field` has a mapping.
+    pub fn set_language_analyzers(
+        &mut self,
+        field: &str,
+        language_field: String,
+        analyzers: &[(Language, &str)],
+    ) -> IndexingResult<()> {
+        if self.mappings.fields.contains_key(&language_field) {
+            return Err(error::IndexingError::MappingFieldAlreadyExists {
+                field: language_field,
+            });
+        }
+        let mut routing = LanguageRouting {
+            field: language_field,
+            analyzers: HashMap::new(),
+        };
+        for &(language, analyzer) in analyzers {
+            routing
+                .analyzers
+                .insert(language, self.get_analyzer(analyzer)?);
+        }
+        match Arc::make_mut(&mut self.mappings).fields.get_mut(field) {
+            Some(mapping) => mapping.languages = Some(routing),
+            None => {
+                return Err(error::IndexingError::MissingFieldMapping {
+                    field: field.to_string(),
+                })
+            }
+        }
+        if self.language_detector.is_none() {
+            self.language_detector = Some(LanguageDetector::new());
+        }
+        Ok(())
+    }
+
+    /// Registers the analyzers and sets the mappings of the fields described by the given JSON
+    /// document, see [`config`].
+    ///
+    /// # Errors
+    ///
+    /// An [`error::IndexingError::InvalidConfig`] error is returned if the JSON document is not a
+    /// valid configuration, in addition to the errors of [`Index::register_analyzer`],
+    /// [`Index::set_analyzer`] and [`Index::set_search_analyzer`].
+    pub fn configure(&mut self, json: &str) -> IndexingResult<()> {
+        let config = config::parse_json(json)?;
+        self.apply_config(config)
+    }
+
+    /// Registers the analyzers and sets the mappings of the fields of the given configuration,
+    /// e.g., deserialized from another format than JSON, see [`config`].
+    ///
+    /// The configuration is applied as a whole: the index is left unchanged if it fails.
+    ///
+    /// # Errors
+    ///
+    /// The errors are those of [`Index::configure`].
+    pub fn apply_config(&mut self, config: config::IndexConfig) -> IndexingResult<()> {
+        let mut analyzers = Vec::with_capacity(config.analyzers.len());
+        for (name, analyzer) in config.analyzers {
+            let path = format!("analyzers.{}", name);
+            analyzers.push((name, analyzer.into_analyzer(&path).map_err(config::invalid)?));
+        }
+        // the current mappings are kept as is, as they are copied on the first change
+        let previous_analyzers = self.analyzers.clone();
+        let previous_mappings = Arc::clone(&self.mappings);
+        let result = self.apply_analyzers(analyzers, config.fields);
+        if result.is_err() {
+            self.analyzers = previous_analyzers;
+            self.mappings = previous_mappings;
+        }
+        result
+    }
+
+    fn apply_analyzers(
+        &mut self,
+        analyzers: Vec<(String, Analyzer)>,
+        fields: BTreeMap<String, config::FieldConfig>,
+    ) -> IndexingResult<()> {
+        for (name, analyzer) in analyzers {
+            self.register_analyzer(name, analyzer)?;
+        }
+        for (field, mapping) in fields {
+            self.set_analyzer(field.clone(), &mapping.analyzer)?;
+            if let Some(ref analyzer) = mapping.search_analyzer {
+                self.set_search_analyzer(&field, analyzer)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Stores the offsets of the tokens of the specified field in the posting lists.
+    ///
+    /// # Errors
+    ///
+    /// An [`error::IndexingError::MissingFieldMapping`] error is returned if the field has no
+    /// mapping defined, and an [`error::IndexingError::DocumentsAlreadyIndexed`] error if
+    /// documents were already added to the index.
+    pub fn store_offsets(&mut self, field: &str) -> IndexingResult<()> {
+        if !self.mappings.fields.contains_key(field) {
+            return Err(error::IndexingError::MissingFieldMapping {
+                field: field.to_string(),
+            });
+        }
+        if self.num_docs() > 0 {
+            return Err(error::IndexingError::DocumentsAlreadyIndexed {
+                field: field.to_string(),
+            });
+        }
+        self.offsets.insert(field.to_string());
+        Ok(())
+    }
+
+    /// Stores a [`term_vectors::TermVector`] of the specified field for each added document.
+    ///
+    /// # Errors
+    ///
+    /// An [`error::IndexingError::MissingFieldMapping`] error is returned if the field has no
+    /// mapping defined.
+    pub fn store_term_vectors(&mut self, field: &str) -> IndexingResult<()> {
+        if !self.mappings.fields.contains_key(field) {
+            return Err(error::IndexingError::MissingFieldMapping {
+                field: field.to_string(),
+            });
+        }
+        self.term_vectors.insert(field.to_string());
+        Ok(())
+    }
+
+    /// Sets the maximum length of the terms, in characters, over which documents are rejected.
+    ///
+    /// Unlike a [`TokenFilter::Length`][length] filter, which silently drops the tokens, this
+    /// allows to find out about pathological inputs, e.g., encoded binary data.
+    ///
+    /// [length]: ../tokenizer/filter/enum.TokenFilter.html#variant.Length
+    pub fn set_max_term_length(&mut self, max_length: usize) {
+        self.max_term_length = Some(max_length);
+    }
+
     /// Adds the given document to the index.
     ///
+    /// The document is analyzed before any of its terms is indexed, so that nothing is indexed
+    /// if an error is returned.
+    ///
     /// # Errors
     ///
     /// An [`error::IndexingError::MissingFieldMapping`] error is returned if the document contains
-    /// a field that has no mapping defined.
+    /// a field that has no mapping defined, and an [`error::IndexingError::TermTooLong`] error if
+    /// one of its terms exceeds the maximum term length.
     pub fn add_doc(&mut self, doc: &document::Document) -> IndexingResult<()> {
+        let mut languages = HashSet::new();
+        let mut fields = Vec::new();
         for field in doc.fields() {
-            if !self.mappings.contains_key(field.field) {
-                return Err(error::IndexingError::MissingFieldMapping {
-                    field: field.field.to_string(),
-                });
+            let mapping = match self.mappings.fields.get(field.field) {
+                Some(mapping) => mapping,
+                None => {
+                    return Err(error::IndexingError::MissingFieldMapping {
+                        field: field.field.to_string(),
+                    })
+                }
+            };
+            let (tokenizer, language) =
+                mapping.route(self.language_detector.as_ref(), field.value);
+            if let (Some(routing), Some(language)) = (mapping.languages.as_ref(), language) {
+                languages.insert((&routing.field, language));
             }
-            let tokenizer = &self.mappings[field.field];
-            for token in tokenizer.tokenize(field.value) {
-                let posting = self.postings
-                    .entry(format!("{}:{}", field.field, token.token))
-                    .or_insert_with(posting_lists::new);
-                posting.add_token(self.doc_id, token.position);
+            let tokens: Vec<Token> = tokenizer.tokenize(field.value).collect();
+            if let Some(max_length) = self.max_term_length {
+                for token in &tokens {
+                    let length = token.token.chars().count();
+                    if length > max_length {
+                        return Err(error::IndexingError::TermTooLong {
+                            doc_id: self.num_docs(),
+                            field: field.field.to_string(),
+                            prefix: token.token.chars().take(max_length).collect(),
+                            length,
+                            max_length,
+                        });
+                    }
+                }
             }
+            fields.push((field.field, tokens));
+        }
+        let segment = self.writer.buffer();
+        for (field, tokens) in fields {
+            segment.add_tokens(
+                field,
+                tokens,
+                self.offsets.contains(field),
+                self.term_vectors.contains(field),
+            );
+        }
+        for (field, language) in languages {
+            segment.add_term(format!("{}:{}", field, language.name()), 1);
         }
-        self.doc_id += 1;
+        self.writer.end_doc();
         Ok(())
     }
 
-    /// Returns the posting lists associated with the given field.
+    /// Returns the stages of the tokenization of the given text by the tokenizer of the specified
+    /// field, as done when indexing it, see [`analysis`][analysis].
     ///
-    /// If the index does not have a posting lists for that field, then an [`posting_lists::empty`]
-    /// posting is returned.
-    pub fn get_postings_list(&self, field: &str) -> Box<&Posting> {
-        if !self.postings.contains_key(field) {
-            Box::new(posting_lists::empty())
-        } else {
-            Box::new(&self.postings[field])
+    /// # Errors
+    ///
+    /// An [`error::IndexingError::MissingFieldMapping`] error is returned if the field has no
+    /// mapping defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ::iryfful::index::Index;
+    /// use ::iryfful::tokenizer::filter::TokenFilter;
+    /// use ::iryfful::tokenizer::language::Language;
+    /// use ::iryfful::tokenizer::Tokenizer;
+    /// use ::iryfful::tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+    ///
+    /// let mut tokenizer = WhiteSpaceTokenizer::new();
+    /// tokenizer.add_filter(TokenFilter::stop_words(Language::English));
+    /// tokenizer.add_filter(TokenFilter::LowerCase);
+    /// let mut index: Index = Default::default();
+    /// index.set_mapping(String::from("title"), tokenizer).unwrap();
+    ///
+    /// // the stop words are removed before lowercasing, hence "The" is kept
+    /// let analysis = index.analyze("title", "The art").unwrap();
+    /// assert_eq!(analysis.tokens[0].token.token, "the");
+    /// assert_eq!(analysis.tokens[0].filters, vec!["filters[1].lowercase"]);
+    /// assert!(analysis.tokens[1].filters.is_empty());
+    /// ```
+    ///
+    /// [analysis]: ../tokenizer/analysis/index.html
+    pub fn analyze(&self, field: &str, text: &str) -> IndexingResult<Analysis> {
+        match self.mappings.fields.get(field) {
+            Some(mapping) => Ok(mapping
+                .route(self.language_detector.as_ref(), text)
+                .0
+                .analyze(text)),
+            None => Err(error::IndexingError::MissingFieldMapping {
+                field: field.to_string(),
+            }),
         }
     }
+
+    /// Returns the tokenizer set for the specified field, if any.
+    pub fn get_tokenizer(&self, field: &str) -> Option<&(Tokenizer + 'a)> {
+        self.mappings.get_tokenizer(field)
+    }
+
+    /// Returns the tokenizer used on the query terms of the specified field, if any, i.e., its
+    /// search analyzer or else its tokenizer.
+    pub fn get_search_tokenizer(&self, field: &str) -> Option<&(Tokenizer + 'a)> {
+        self.mappings.get_search_tokenizer(field)
+    }
+
+    /// Returns the term to look up in the specified field for the given query term.
+    ///
+    /// The term is normalized with the search tokenizer of the field if it is a normalizer,
+    /// otherwise it is returned as is.
+    pub fn normalize<'t>(&self, field: &str, term: &'t str) -> Cow<'t, str> {
+        self.mappings.normalize(field, term)
+    }
+
+    /// Returns the [`term_vectors::TermVector`] of the specified field for the document with the
+    /// given ID.
+    ///
+    /// `None` is returned if term vectors are not stored for that field, or if the document does
+    /// not exist or has no value for that field.
+    pub fn term_vector(&self, doc_id: u32, field: &str) -> Option<&term_vectors::TermVector> {
+        let segments = self.writer.segments().into_iter().map(|segment| &**segment);
+        reader::term_vector(segments, doc_id, field)
+    }
+
+    /// Returns the number of documents added to the index.
+    pub fn num_docs(&self) -> u32 {
+        self.writer.num_docs()
+    }
+
+    /// Returns the posting lists associated with the given field, over all the segments.
+    pub fn get_postings_list(&self, field: &str) -> SegmentedPosting<'_> {
+        let segments = self.writer.segments().into_iter().map(|segment| &**segment);
+        reader::get_postings_list(segments, field)
+    }
+
+    /// Returns the writer of the segments of the index, e.g., to flush the buffered documents or
+    /// set the merge policy.
+    pub fn writer(&mut self) -> &mut writer::IndexWriter {
+        &mut self.writer
+    }
+
+    /// Opens a reader over the documents added so far, which is not affected by the documents
+    /// added afterwards.
+    pub fn reader(&self) -> reader::IndexReader<'a> {
+        let segments = self.writer.snapshot();
+        reader::IndexReader::new(self.mappings.clone(), segments)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use index::posting_lists::DocItem;
+    use search::query::Query;
+    use search::query::term_query::TermQuery;
+    use search::{IndexSearcher, SearchHit};
     use tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
 
     #[test]
@@ -136,13 +611,324 @@ mod tests {
         doc.add_field("field1", "bbb");
         index.add_doc(&doc).unwrap();
 
-        assert_eq!(index.postings.len(), 2);
-        for (key, posting) in index.postings.iter() {
-            match key.as_ref() {
-                "field1:aaa" => assert_eq!(posting.len(), 1),
-                "field1:bbb" => assert_eq!(posting.len(), 2),
-                _ => panic!(format!("got unexpected key={}", key)),
+        assert_eq!(index.writer().segments()[0].num_terms(), 2);
+        assert_eq!(index.get_postings_list("field1:aaa").len(), 1);
+        assert_eq!(index.get_postings_list("field1:bbb").len(), 2);
+    }
+
+    #[test]
+    fn should_store_offsets() {
+        let mut index: Index = Default::default();
+        index
+            .set_mapping(String::from("field1"), WhiteSpaceTokenizer::new())
+            .unwrap();
+        index
+            .set_mapping(String::from("field2"), WhiteSpaceTokenizer::new())
+            .unwrap();
+        index.store_offsets("field1").unwrap();
+        assert!(index.store_offsets("field3").is_err());
+
+        let mut doc: document::Document = Default::default();
+        doc.add_field("field1", "aaa bbb aaa");
+        doc.add_field("field2", "aaa");
+        index.add_doc(&doc).unwrap();
+
+        let posting = index.get_postings_list("field1:aaa");
+        let item = posting.iter_docs_pos().next().unwrap();
+        assert_eq!(item.offsets, &[(0, 3), (8, 11)]);
+
+        let posting = index.get_postings_list("field2:aaa");
+        let item = posting.iter_docs_pos().next().unwrap();
+        assert!(item.offsets.is_empty());
+
+        // the posting lists of field2 would mix tokens with and without offsets
+        match index.store_offsets("field2") {
+            Err(error::IndexingError::DocumentsAlreadyIndexed { field }) => {
+                assert_eq!(field, "field2")
+            }
+            res => panic!("expected already indexed documents, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn should_store_term_vectors() {
+        let mut index: Index = Default::default();
+        index
+            .set_mapping(String::from("field1"), WhiteSpaceTokenizer::new())
+            .unwrap();
+        index
+            .set_mapping(String::from("field2"), WhiteSpaceTokenizer::new())
+            .unwrap();
+        index.store_term_vectors("field1").unwrap();
+        assert!(index.store_term_vectors("field3").is_err());
+
+        let mut doc: document::Document = Default::default();
+        doc.add_field("field1", "aaa bbb aaa");
+        doc.add_field("field2", "aaa");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("field2", "bbb");
+        index.add_doc(&doc).unwrap();
+
+        let term_vector = index.term_vector(0, "field1").unwrap();
+        assert_eq!(term_vector.len(), 2);
+        let aaa = term_vector.get("aaa").unwrap();
+        assert_eq!(aaa.freq, 2);
+        assert_eq!(aaa.positions, vec![1, 3]);
+        assert_eq!(aaa.offsets, vec![(0, 3), (8, 11)]);
+
+        assert!(index.term_vector(0, "field2").is_none());
+        assert!(index.term_vector(1, "field1").is_none());
+    }
+
+    #[test]
+    fn should_share_registered_analyzers() {
+        let mut index: Index = Default::default();
+        index
+            .register_analyzer(String::from("ws"), WhiteSpaceTokenizer::new())
+            .unwrap();
+        assert!(
+            index
+                .register_analyzer(String::from("ws"), WhiteSpaceTokenizer::new())
+                .is_err()
+        );
+        index.set_analyzer(String::from("field1"), "ws").unwrap();
+        index.set_analyzer(String::from("field2"), "ws").unwrap();
+        assert!(index.set_analyzer(String::from("field1"), "ws").is_err());
+        assert!(index.set_analyzer(String::from("field3"), "std").is_err());
+        assert!(index.set_search_analyzer("field3", "ws").is_err());
+        match index.set_search_analyzer("field1", "ws") {
+            Err(error::IndexingError::InvalidSearchAnalyzer { name }) => assert_eq!(name, "ws"),
+            res => panic!("expected an invalid search analyzer, got {:?}", res),
+        }
+
+        let mut doc: document::Document = Default::default();
+        doc.add_field("field1", "aaa bbb");
+        doc.add_field("field2", "aaa");
+        index.add_doc(&doc).unwrap();
+
+        assert_eq!(index.get_postings_list("field1:bbb").len(), 1);
+        assert_eq!(index.get_postings_list("field2:aaa").len(), 1);
+    }
+
+    #[test]
+    fn should_normalize_with_the_search_analyzer() {
+        let mut index: Index = Default::default();
+        index
+            .configure(
+                r#"{
+                "analyzers": {
+                    "tag": {"tokenizer": "keyword", "filters": ["lowercase"]},
+                    "folded_tag": {"tokenizer": "keyword", "filters": [
+                        "lowercase", {"ascii_folding": {}}
+                    ]}
+                },
+                "fields": {
+                    "field1": {"analyzer": "tag"},
+                    "field2": {"analyzer": "tag", "search_analyzer": "folded_tag"}
+                }
+            }"#,
+            )
+            .unwrap();
+
+        assert_eq!(index.normalize("field1", "Café"), "café");
+        assert_eq!(index.normalize("field2", "Café"), "cafe");
+        assert_eq!(index.normalize("field3", "Café"), "Café");
+    }
+
+    #[test]
+    fn should_analyze_values_in_their_language() {
+        let mut index: Index = Default::default();
+        index
+            .configure(
+                r#"{
+                "analyzers": {
+                    "default": {"tokenizer": "standard", "filters": ["lowercase"]},
+                    "english": {"tokenizer": "standard", "filters": [
+                        "lowercase", {"stemmer": "porter"}
+                    ]},
+                    "french": {"tokenizer": "standard", "filters": [
+                        "lowercase", {"stemmer": "french"}
+                    ]}
+                },
+                "fields": {"body": {"analyzer": "default"}}
+            }"#,
+            )
+            .unwrap();
+        let analyzers = [(Language::English, "english"), (Language::French, "french")];
+        assert!(
+            index
+                .set_language_analyzers("title", String::from("lang"), &analyzers)
+                .is_err()
+        );
+        assert!(
+            index
+                .set_language_analyzers("body", String::from("lang"), &[(Language::German, "de")])
+                .is_err()
+        );
+        assert!(
+            index
+                .set_language_analyzers("body", String::from("body"), &analyzers)
+                .is_err()
+        );
+        index
+            .set_language_analyzers("body", String::from("lang"), &analyzers)
+            .unwrap();
+        assert!(
+            index
+                .set_mapping(String::from("lang"), WhiteSpaceTokenizer::new())
+                .is_err()
+        );
+
+        let mut doc: document::Document = Default::default();
+        doc.add_field("body", "The children are playing in the gardens");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("body", "Les enfants jouent dans les jardins");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("body", "42");
+        index.add_doc(&doc).unwrap();
+
+        assert_eq!(index.get_postings_list("body:garden").len(), 1);
+        assert_eq!(index.get_postings_list("body:jardin").len(), 1);
+        assert_eq!(index.get_postings_list("body:42").len(), 1);
+
+        let english: Vec<u32> = index
+            .get_postings_list("lang:english")
+            .iter_docs()
+            .map(|item| item.get_doc_id())
+            .collect();
+        assert_eq!(english, vec![0]);
+        let french: Vec<u32> = index
+            .get_postings_list("lang:french")
+            .iter_docs()
+            .map(|item| item.get_doc_id())
+            .collect();
+        assert_eq!(french, vec![1]);
+
+        let tq = TermQuery::new("lang", "english");
+        let hits: Vec<SearchHit> = tq.execute(&IndexSearcher::new(&index)).collect();
+        assert_eq!(hits, vec![SearchHit::new(0)]);
+    }
+
+    #[test]
+    fn should_reject_documents_with_too_long_terms() {
+        let mut index: Index = Default::default();
+        index
+            .set_mapping(String::from("field1"), WhiteSpaceTokenizer::new())
+            .unwrap();
+        index
+            .set_mapping(String::from("field2"), WhiteSpaceTokenizer::new())
+            .unwrap();
+        index.set_max_term_length(8);
+
+        let mut doc: document::Document = Default::default();
+        doc.add_field("field1", "ccc");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("field1", "aaa");
+        doc.add_field("field2", "bbb QUJDREVGR0hJSktMTU5PUA==");
+        let err = index.add_doc(&doc).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "term of 24 characters in field field2 of document 1 exceeds the maximum length of 8: \
+             QUJDREVG..."
+        );
+        match err {
+            error::IndexingError::TermTooLong {
+                doc_id,
+                field,
+                prefix,
+                length,
+                max_length,
+            } => {
+                assert_eq!(doc_id, 1);
+                assert_eq!(field, "field2");
+                assert_eq!(prefix, "QUJDREVG");
+                assert_eq!(length, 24);
+                assert_eq!(max_length, 8);
             }
+            err => panic!("expected a too long term, got {:?}", err),
+        }
+        // nothing is indexed of a rejected document
+        assert_eq!(index.num_docs(), 1);
+        assert!(index.get_postings_list("field1:aaa").is_empty());
+
+        doc.clear();
+        doc.add_field("field1", "aaa");
+        doc.add_field("field2", "bbbbbbbb");
+        index.add_doc(&doc).unwrap();
+
+        let posting = index.get_postings_list("field2:bbbbbbbb");
+        let item = posting.iter_docs_pos().next().unwrap();
+        assert_eq!(item.get_doc_id(), 1);
+    }
+
+    #[test]
+    fn should_read_documents_across_segments() {
+        let mut index: Index = Default::default();
+        index
+            .set_mapping(String::from("field1"), WhiteSpaceTokenizer::new())
+            .unwrap();
+        index.store_term_vectors("field1").unwrap();
+        index.writer().set_max_buffered_docs(2);
+
+        let mut doc: document::Document = Default::default();
+        for value in &["aaa", "bbb", "aaa bbb", "ccc", "aaa"] {
+            doc.clear();
+            doc.add_field("field1", value);
+            index.add_doc(&doc).unwrap();
         }
+        let reader = index.reader();
+
+        doc.clear();
+        doc.add_field("field1", "aaa");
+        index.add_doc(&doc).unwrap();
+
+        // the reader sees the buffered document but not the one added afterwards
+        assert_eq!(reader.segments().len(), 3);
+        assert_eq!(reader.num_docs(), 5);
+        assert_eq!(index.num_docs(), 6);
+        let docs: Vec<u32> = reader
+            .get_postings_list("field1:aaa")
+            .iter_docs()
+            .map(|item| item.get_doc_id())
+            .collect();
+        assert_eq!(docs, vec![0, 2, 4]);
+        assert_eq!(index.get_postings_list("field1:aaa").len(), 4);
+
+        let term_vector = reader.term_vector(3, "field1").unwrap();
+        assert!(term_vector.get("ccc").is_some());
+        assert!(reader.term_vector(5, "field1").is_none());
+        assert!(index.term_vector(5, "field1").is_some());
+    }
+
+    #[test]
+    fn should_not_flush_segments_on_searches() {
+        let mut index: Index = Default::default();
+        index
+            .set_mapping(String::from("field1"), WhiteSpaceTokenizer::new())
+            .unwrap();
+
+        let mut doc: document::Document = Default::default();
+        let mut searchers = Vec::new();
+        for i in 0..50 {
+            doc.clear();
+            doc.add_field("field1", "aaa");
+            index.add_doc(&doc).unwrap();
+            searchers.push(IndexSearcher::new(&index));
+            assert_eq!(searchers[i].get_reader().num_docs(), i as u32 + 1);
+        }
+
+        // the searchers keep their view of the in-memory segment, which is not flushed
+        assert_eq!(index.writer().segments().len(), 1);
+        let tq = TermQuery::new("field1", "aaa");
+        assert_eq!(tq.execute(&searchers[0]).count(), 1);
+        assert_eq!(tq.execute(&searchers[49]).count(), 50);
     }
 }
@@ -14,6 +14,12 @@ pub trait Posting {
     /// that document.
     fn add_token(&mut self, doc_id: u32, position: u32);
 
+    /// Adds a token as with [`Posting::add_token`], also storing the start and end byte offsets of
+    /// the token in the field's value.
+    ///
+    /// Offsets should be stored for all the tokens of a posting lists or for none.
+    fn add_token_with_offsets(&mut self, doc_id: u32, position: u32, offsets: (u32, u32));
+
     /// Creates an iterator over [`DocIdItem`]s.
     fn iter_docs<'a>(&'a self) -> Box<Iterator<Item = DocIdItem> + 'a>;
 
@@ -26,6 +32,7 @@ pub fn new() -> PostingImpl {
     PostingImpl {
         docs: Vec::new(),
         positions: Vec::new(),
+        offsets: Vec::new(),
     }
 }
 
@@ -49,6 +56,8 @@ impl Posting for EmptyPosting {
 
     fn add_token(&mut self, _doc_id: u32, _position: u32) {}
 
+    fn add_token_with_offsets(&mut self, _doc_id: u32, _position: u32, _offsets: (u32, u32)) {}
+
     fn iter_docs<'a>(&'a self) -> Box<Iterator<Item = DocIdItem> + 'a> {
         Box::new(iter::empty::<DocIdItem>())
     }
@@ -58,13 +67,14 @@ impl Posting for EmptyPosting {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct PostingImpl {
     docs: Vec<DocEntry>,
     positions: Vec<u32>,
+    offsets: Vec<(u32, u32)>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct DocEntry {
     doc_id: u32,
     freqs: u32,
@@ -104,6 +114,8 @@ impl DocItem for DocIdItem {
 pub struct DocIdAndPosItem<'a> {
     doc_id: u32,
     pub positions: &'a [u32],
+    /// The start and end offsets of each occurrence, empty if the offsets are not stored.
+    pub offsets: &'a [(u32, u32)],
 }
 
 impl<'a> DocItem for DocIdAndPosItem<'a> {
@@ -140,6 +152,11 @@ impl Posting for PostingImpl {
         self.positions.push(position);
     }
 
+    fn add_token_with_offsets(&mut self, doc_id: u32, position: u32, offsets: (u32, u32)) {
+        self.add_token(doc_id, position);
+        self.offsets.push(offsets);
+    }
+
     fn iter_docs<'a>(&'a self) -> Box<Iterator<Item = DocIdItem> + 'a> {
         Box::new(self.docs.iter().map(|doc| DocIdItem { doc_id: doc.doc_id }))
     }
@@ -151,11 +168,57 @@ impl Posting for PostingImpl {
             DocIdAndPosItem {
                 doc_id: doc.doc_id,
                 positions: &self.positions[start..end],
+                offsets: self.offsets.get(start..end).unwrap_or(&[]),
             }
         }))
     }
 }
 
+/// The posting lists of a term in several segments of an index, seen as a single posting lists.
+///
+/// The document IDs of each segment's posting lists are shifted by the base of the segment, so
+/// that the documents are iterated over in order of their ID in the index.
+pub struct SegmentedPosting<'a> {
+    postings: Vec<(u32, &'a PostingImpl)>,
+}
+
+impl<'a> SegmentedPosting<'a> {
+    /// Creates a posting lists over the given pairs of segment base and posting lists, ordered by
+    /// base.
+    pub fn new(postings: Vec<(u32, &'a PostingImpl)>) -> SegmentedPosting<'a> {
+        SegmentedPosting { postings }
+    }
+
+    /// Returns `true` if there is no token in this posting lists.
+    pub fn is_empty(&self) -> bool {
+        self.postings.iter().all(|&(_, posting)| posting.is_empty())
+    }
+
+    /// Returns the number of documents this posting lists contains.
+    pub fn len(&self) -> usize {
+        self.postings.iter().map(|&(_, posting)| posting.len()).sum()
+    }
+
+    /// Creates an iterator over [`DocIdItem`]s.
+    pub fn iter_docs(&self) -> Box<Iterator<Item = DocIdItem> + 'a> {
+        Box::new(self.postings.clone().into_iter().flat_map(|(base, posting)| {
+            posting.iter_docs().map(move |doc| DocIdItem {
+                doc_id: base + doc.doc_id,
+            })
+        }))
+    }
+
+    /// Creates an iterator over [`DocIdAndPosItem`]s.
+    pub fn iter_docs_pos(&self) -> Box<Iterator<Item = DocIdAndPosItem<'a>> + 'a> {
+        Box::new(self.postings.clone().into_iter().flat_map(|(base, posting)| {
+            posting.iter_docs_pos().map(move |doc| DocIdAndPosItem {
+                doc_id: base + doc.doc_id,
+                ..doc
+            })
+        }))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -233,4 +296,54 @@ mod tests {
         let next = iter.next();
         assert_eq!(next.is_none(), true);
     }
+
+    #[test]
+    fn test_iter_docs_offsets() {
+        let mut posting = new();
+        posting.add_token_with_offsets(1, 42, (120, 123));
+        posting.add_token_with_offsets(1, 45, (130, 133));
+        posting.add_token_with_offsets(3, 2, (4, 7));
+
+        let mut iter = posting.iter_docs_pos();
+
+        let next = iter.next().unwrap();
+        assert_eq!(next.positions, &[42, 45]);
+        assert_eq!(next.offsets, &[(120, 123), (130, 133)]);
+
+        let next = iter.next().unwrap();
+        assert_eq!(next.positions, &[2]);
+        assert_eq!(next.offsets, &[(4, 7)]);
+
+        // offsets are not stored
+        let mut posting = new();
+        posting.add_token(1, 42);
+
+        let next = posting.iter_docs_pos().next().unwrap();
+        assert!(next.offsets.is_empty());
+    }
+
+    #[test]
+    fn test_segmented_posting() {
+        let mut posting1 = new();
+        posting1.add_token(0, 3);
+        posting1.add_token(2, 1);
+        let mut posting2 = new();
+        posting2.add_token(1, 2);
+        posting2.add_token(1, 5);
+
+        let posting = SegmentedPosting::new(vec![(0, &posting1), (4, &posting2)]);
+        assert_eq!(posting.len(), 3);
+        assert!(!posting.is_empty());
+
+        let docs: Vec<u32> = posting.iter_docs().map(|doc| doc.doc_id).collect();
+        assert_eq!(docs, vec![0, 2, 5]);
+
+        let docs: Vec<(u32, &[u32])> = posting
+            .iter_docs_pos()
+            .map(|doc| (doc.doc_id, doc.positions))
+            .collect();
+        assert_eq!(docs, vec![(0, &[3][..]), (2, &[1][..]), (5, &[2, 5][..])]);
+
+        assert!(SegmentedPosting::new(Vec::new()).is_empty());
+    }
 }
@@ -0,0 +1,95 @@
+//! Reading of the segments of an index.
+//!
+//! An [`IndexReader`] is a snapshot of an [`Index`][index]: it sees the documents and mappings of
+//! the index as they were when it was opened with [`Index::reader`][reader], while documents can
+//! still be added to the index. The documents of its segments are identified by their ID within
+//! their segment plus the base of the segment, see [`segment`][segment].
+//!
+//! [index]: ../struct.Index.html
+//! [reader]: ../struct.Index.html#method.reader
+//! [segment]: ../segment/index.html
+use index::Mappings;
+use index::posting_lists::SegmentedPosting;
+use index::segment::Segment;
+use index::term_vectors::TermVector;
+use std::borrow::Cow;
+use std::sync::Arc;
+use tokenizer::Tokenizer;
+
+/// The `IndexReader` type provides a read-only view over the segments of an index.
+#[derive(Clone)]
+pub struct IndexReader<'a> {
+    mappings: Arc<Mappings<'a>>,
+    segments: Vec<Arc<Segment>>,
+}
+
+impl<'a> IndexReader<'a> {
+    pub(super) fn new(mappings: Arc<Mappings<'a>>, segments: Vec<Arc<Segment>>) -> IndexReader<'a> {
+        IndexReader { mappings, segments }
+    }
+
+    /// Returns the segments of the index, from the oldest to the newest.
+    pub fn segments(&self) -> &[Arc<Segment>] {
+        &self.segments
+    }
+
+    /// Returns the number of documents of the index.
+    pub fn num_docs(&self) -> u32 {
+        self.segments.iter().map(|segment| segment.num_docs()).sum()
+    }
+
+    /// Returns the posting lists of the given term, e.g., `field1:aaa`, over all segments.
+    pub fn get_postings_list(&self, term: &str) -> SegmentedPosting<'_> {
+        get_postings_list(self.segments.iter().map(|segment| &**segment), term)
+    }
+
+    /// Returns the [`TermVector`] of the specified field for the document with the given ID.
+    pub fn term_vector(&self, doc_id: u32, field: &str) -> Option<&TermVector> {
+        term_vector(self.segments.iter().map(|segment| &**segment), doc_id, field)
+    }
+
+    /// Returns the tokenizer set for the specified field, if any.
+    pub fn get_tokenizer(&self, field: &str) -> Option<&(Tokenizer + 'a)> {
+        self.mappings.get_tokenizer(field)
+    }
+
+    /// Returns the term to look up in the specified field for the given query term, see
+    /// [`Index::normalize`][normalize].
+    ///
+    /// [normalize]: ../struct.Index.html#method.normalize
+    pub fn normalize<'t>(&self, field: &str, term: &'t str) -> Cow<'t, str> {
+        self.mappings.normalize(field, term)
+    }
+}
+
+/// Returns the posting lists of the given term over the segments.
+pub(super) fn get_postings_list<'s, I>(segments: I, term: &str) -> SegmentedPosting<'s>
+where
+    I: Iterator<Item = &'s Segment>,
+{
+    let mut base = 0;
+    let mut postings = Vec::new();
+    for segment in segments {
+        if let Some(posting) = segment.get_postings_list(term) {
+            postings.push((base, posting));
+        }
+        base += segment.num_docs();
+    }
+    SegmentedPosting::new(postings)
+}
+
+/// Returns the term vector of the specified field for the document with the given ID among the
+/// segments.
+pub(super) fn term_vector<'s, I>(segments: I, doc_id: u32, field: &str) -> Option<&'s TermVector>
+where
+    I: Iterator<Item = &'s Segment>,
+{
+    let mut base = 0;
+    for segment in segments {
+        if doc_id < base + segment.num_docs() {
+            return segment.term_vector(doc_id - base, field);
+        }
+        base += segment.num_docs();
+    }
+    None
+}
@@ -0,0 +1,186 @@
+//! Immutable parts of an index.
+//!
+//! A [`Segment`] holds the posting lists and term vectors of a range of consecutive documents,
+//! identified from 0 within the segment. The [`IndexWriter`][writer] buffers the added documents
+//! into an in-memory segment, which is flushed as is once full, and merges the small segments
+//! into larger ones according to its [`MergePolicy`][policy].
+//!
+//! The ID of a document in the index is its ID within its segment plus the base of the segment,
+//! i.e., the number of documents in the segments before it. Since only consecutive segments are
+//! merged, a document keeps its ID across merges.
+//!
+//! [writer]: ../writer/struct.IndexWriter.html
+//! [policy]: ../merge_policy/trait.MergePolicy.html
+use index::posting_lists;
+use index::posting_lists::{DocItem, Posting, PostingImpl};
+use index::term_vectors::TermVector;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokenizer::Token;
+
+/// The posting lists and term vectors of a range of documents.
+#[derive(Clone, Debug, Default)]
+pub struct Segment {
+    num_docs: u32,
+    postings: HashMap<String, PostingImpl>,
+    term_vectors: HashMap<String, HashMap<u32, TermVector>>,
+}
+
+impl Segment {
+    /// Returns the number of documents of this segment.
+    pub fn num_docs(&self) -> u32 {
+        self.num_docs
+    }
+
+    /// Returns the number of distinct terms of this segment, over all fields.
+    pub fn num_terms(&self) -> usize {
+        self.postings.len()
+    }
+
+    /// Returns the posting lists of the given term, e.g., `field1:aaa`, if it occurs in this
+    /// segment.
+    pub fn get_postings_list(&self, term: &str) -> Option<&PostingImpl> {
+        self.postings.get(term)
+    }
+
+    /// Returns the term vector of the specified field for the document with the given ID within
+    /// this segment.
+    pub fn term_vector(&self, doc_id: u32, field: &str) -> Option<&TermVector> {
+        self.term_vectors
+            .get(field)
+            .and_then(|term_vectors| term_vectors.get(&doc_id))
+    }
+
+    /// Merges the given consecutive segments into a single one, whose documents are those of the
+    /// segments in order.
+    pub fn merge(segments: &[Arc<Segment>]) -> Segment {
+        let mut merged: Segment = Default::default();
+        for segment in segments {
+            let base = merged.num_docs;
+            for (term, posting) in &segment.postings {
+                let merged_posting = merged
+                    .postings
+                    .entry(term.clone())
+                    .or_insert_with(posting_lists::new);
+                for doc in posting.iter_docs_pos() {
+                    let doc_id = base + doc.get_doc_id();
+                    for (i, &position) in doc.positions.iter().enumerate() {
+                        match doc.offsets.get(i) {
+                            Some(&offsets) => {
+                                merged_posting.add_token_with_offsets(doc_id, position, offsets)
+                            }
+                            None => merged_posting.add_token(doc_id, position),
+                        }
+                    }
+                }
+            }
+            for (field, term_vectors) in &segment.term_vectors {
+                let merged_term_vectors = merged.term_vectors.entry(field.clone()).or_default();
+                for (doc_id, term_vector) in term_vectors {
+                    merged_term_vectors.insert(base + doc_id, term_vector.clone());
+                }
+            }
+            merged.num_docs += segment.num_docs;
+        }
+        merged
+    }
+
+    /// Indexes the tokens of a field of the document being added, i.e., the document with ID
+    /// [`Segment::num_docs`].
+    pub(super) fn add_tokens(
+        &mut self,
+        field: &str,
+        tokens: Vec<Token>,
+        store_offsets: bool,
+        store_term_vector: bool,
+    ) {
+        let doc_id = self.num_docs;
+        let mut term_vector = if store_term_vector {
+            Some(
+                self.term_vectors
+                    .entry(field.to_string())
+                    .or_default()
+                    .entry(doc_id)
+                    .or_default(),
+            )
+        } else {
+            None
+        };
+        for token in tokens {
+            if let Some(ref mut term_vector) = term_vector {
+                term_vector.add_token(&token);
+            }
+            let posting = self.postings
+                .entry(format!("{}:{}", field, token.token))
+                .or_insert_with(posting_lists::new);
+            if store_offsets {
+                posting.add_token_with_offsets(
+                    doc_id,
+                    token.position,
+                    (token.start_offset, token.end_offset),
+                );
+            } else {
+                posting.add_token(doc_id, token.position);
+            }
+        }
+    }
+
+    /// Indexes a term at the given position in the document being added.
+    pub(super) fn add_term(&mut self, term: String, position: u32) {
+        let doc_id = self.num_docs;
+        self.postings
+            .entry(term)
+            .or_insert_with(posting_lists::new)
+            .add_token(doc_id, position);
+    }
+
+    /// Ends the document being added.
+    pub(super) fn end_doc(&mut self) {
+        self.num_docs += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokenizer::Tokenizer;
+    use tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+
+    fn segment(values: &[&str]) -> Arc<Segment> {
+        let tokenizer = WhiteSpaceTokenizer::new();
+        let mut segment: Segment = Default::default();
+        for value in values {
+            segment.add_tokens("field1", tokenizer.tokenize(value).collect(), true, true);
+            segment.end_doc();
+        }
+        Arc::new(segment)
+    }
+
+    #[test]
+    fn should_merge_segments() {
+        let merged = Segment::merge(&[
+            segment(&["aaa bbb", "bbb"]),
+            segment(&["ccc"]),
+            segment(&["aaa aaa"]),
+        ]);
+        assert_eq!(merged.num_docs(), 4);
+        assert_eq!(merged.num_terms(), 3);
+
+        let posting = merged.get_postings_list("field1:aaa").unwrap();
+        let mut iter = posting.iter_docs_pos();
+        let doc = iter.next().unwrap();
+        assert_eq!(doc.get_doc_id(), 0);
+        assert_eq!(doc.positions, &[1]);
+        assert_eq!(doc.offsets, &[(0, 3)]);
+        let doc = iter.next().unwrap();
+        assert_eq!(doc.get_doc_id(), 3);
+        assert_eq!(doc.positions, &[1, 2]);
+        assert_eq!(doc.offsets, &[(0, 3), (4, 7)]);
+        assert!(iter.next().is_none());
+        assert!(merged.get_postings_list("field1:ddd").is_none());
+
+        let term_vector = merged.term_vector(2, "field1").unwrap();
+        assert_eq!(term_vector.get("ccc").unwrap().positions, vec![1]);
+        assert!(merged.term_vector(4, "field1").is_none());
+    }
+}
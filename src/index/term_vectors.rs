@@ -0,0 +1,95 @@
+//! Logic for storing the terms of a document's field.
+//!
+//! A [`TermVector`] lists the terms occurring within the field of a single document, along with
+//! their frequencies, positions and offsets. This is useful when the whole content of a document
+//! is needed, e.g., for finding similar documents or highlighting.
+use std::collections::BTreeMap;
+use std::collections::btree_map;
+use tokenizer::Token;
+
+/// The terms of a document's field, ordered lexicographically.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TermVector {
+    terms: BTreeMap<String, TermVectorEntry>,
+}
+
+/// The occurrences of a term within a document's field.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TermVectorEntry {
+    /// The number of times the term occurs.
+    pub freq: u32,
+    /// The position of each occurrence.
+    pub positions: Vec<u32>,
+    /// The start and end offsets of each occurrence.
+    pub offsets: Vec<(u32, u32)>,
+}
+
+impl TermVector {
+    /// Returns `true` if this term vector has no term.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Returns the number of distinct terms of this term vector.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Adds an occurrence of the token's term.
+    pub fn add_token(&mut self, token: &Token) {
+        let entry = self.terms.entry(token.token.clone()).or_default();
+        entry.freq += 1;
+        entry.positions.push(token.position);
+        entry.offsets.push((token.start_offset, token.end_offset));
+    }
+
+    /// Returns the occurrences of the given term, if any.
+    pub fn get(&self, term: &str) -> Option<&TermVectorEntry> {
+        self.terms.get(term)
+    }
+
+    /// Returns an [`Iterator`] over the terms and their occurrences, in lexicographic order.
+    pub fn iter(&self) -> btree_map::Iter<'_, String, TermVectorEntry> {
+        self.terms.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(term: &str, position: u32, start_offset: u32) -> Token {
+        Token {
+            token: String::from(term),
+            position,
+            start_offset,
+            end_offset: start_offset + term.len() as u32,
+            position_length: 1,
+            keyword: false,
+        }
+    }
+
+    #[test]
+    fn should_add_tokens() {
+        let mut term_vector: TermVector = Default::default();
+        assert!(term_vector.is_empty());
+
+        term_vector.add_token(&token("bbb", 1, 0));
+        term_vector.add_token(&token("aaa", 2, 4));
+        term_vector.add_token(&token("bbb", 3, 8));
+
+        assert_eq!(term_vector.len(), 2);
+        assert_eq!(
+            term_vector.get("bbb"),
+            Some(&TermVectorEntry {
+                freq: 2,
+                positions: vec![1, 3],
+                offsets: vec![(0, 3), (8, 11)],
+            })
+        );
+        assert_eq!(term_vector.get("ccc"), None);
+
+        let terms: Vec<&str> = term_vector.iter().map(|(term, _)| &term[..]).collect();
+        assert_eq!(terms, vec!["aaa", "bbb"]);
+    }
+}
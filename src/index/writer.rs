@@ -0,0 +1,377 @@
+//! Writing of the segments of an index.
+//!
+//! The [`IndexWriter`] buffers the documents added to an [`Index`][index] into an in-memory
+//! [`Segment`], which is flushed, i.e., made immutable, once it has reached the maximum number of
+//! buffered documents or on [`IndexWriter::flush`]. After a flush, the segments selected by the
+//! [`MergePolicy`] are merged in a background thread, while the index can still be written to and
+//! searched.
+//!
+//! An [`IndexReader`][reader] sees the segments as they were when it was opened, including the
+//! buffered documents: the in-memory segment is then copied on the next added document. The
+//! finished merges are seen by the readers opened afterwards, and applied to the segments of the
+//! writer on the next added document or flush.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::index::Index;
+//! use ::iryfful::index::document::Document;
+//! use ::iryfful::index::merge_policy::LogSizeMergePolicy;
+//! use ::iryfful::search::IndexSearcher;
+//! use ::iryfful::search::SearchHit;
+//! use ::iryfful::search::query::Query;
+//! use ::iryfful::search::query::term_query::TermQuery;
+//! use ::iryfful::tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+//!
+//! let mut index: Index = Default::default();
+//! index.set_mapping(String::from("field1"), WhiteSpaceTokenizer::new())
+//!     .unwrap();
+//!
+//! let mut policy = LogSizeMergePolicy::new();
+//! policy.set_merge_factor(2);
+//! policy.set_min_merge_docs(2);
+//! let writer = index.writer();
+//! writer.set_merge_policy(policy);
+//! writer.set_max_buffered_docs(1);
+//!
+//! let mut doc: Document = Default::default();
+//! doc.add_field("field1", "aaa");
+//! index.add_doc(&doc).unwrap();
+//!
+//! // the reader does not see the documents added after it was opened
+//! let reader = index.reader();
+//! doc.clear();
+//! doc.add_field("field1", "aaa bbb");
+//! index.add_doc(&doc).unwrap();
+//!
+//! let tq = TermQuery::new("field1", "aaa");
+//! let index_search = &IndexSearcher::from_reader(reader);
+//! assert_eq!(tq.execute(index_search).count(), 1);
+//!
+//! // both single-document segments are merged
+//! index.writer().wait_for_merges();
+//! let index_search = &IndexSearcher::new(&index);
+//! assert_eq!(index_search.get_reader().segments().len(), 1);
+//! let hits: Vec<SearchHit> = tq.execute(index_search).collect();
+//! assert_eq!(hits, vec![SearchHit::new(0), SearchHit::new(1)]);
+//! ```
+//!
+//! [index]: ../struct.Index.html
+//! [reader]: ../reader/struct.IndexReader.html
+use index::merge_policy::{MergePolicy, TieredMergePolicy};
+use index::segment::Segment;
+use std::mem;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::thread::JoinHandle;
+
+/// The `IndexWriter` type manages the segments of an index.
+pub struct IndexWriter {
+    /// The flushed segments, from the oldest to the newest
+    segments: Vec<Arc<Segment>>,
+    /// The in-memory segment buffering the added documents
+    buffer: Arc<Segment>,
+    max_buffered_docs: u32,
+    merge_policy: Box<MergePolicy>,
+    merges: Vec<Merge>,
+}
+
+/// A merge running in a background thread.
+struct Merge {
+    /// The consecutive segments being merged
+    segments: Vec<Arc<Segment>>,
+    handle: JoinHandle<()>,
+    /// The merged segment, set by the thread once finished
+    merged: Arc<Mutex<Option<Arc<Segment>>>>,
+}
+
+impl Merge {
+    fn merged(&self) -> Option<Arc<Segment>> {
+        self.merged
+            .lock()
+            .expect("could not merge the segments")
+            .clone()
+    }
+}
+
+impl Default for IndexWriter {
+    fn default() -> IndexWriter {
+        IndexWriter::new()
+    }
+}
+
+impl IndexWriter {
+    /// Creates a new writer without any segment.
+    ///
+    /// By default, at most 1000 documents are buffered, and the segments are merged according
+    /// to a [`TieredMergePolicy`].
+    pub fn new() -> IndexWriter {
+        IndexWriter {
+            segments: Vec::new(),
+            buffer: Default::default(),
+            max_buffered_docs: 1000,
+            merge_policy: Box::new(TieredMergePolicy::new()),
+            merges: Vec::new(),
+        }
+    }
+
+    /// Sets the policy selecting the segments to merge.
+    pub fn set_merge_policy<P: MergePolicy + 'static>(&mut self, merge_policy: P) {
+        self.merge_policy = Box::new(merge_policy);
+    }
+
+    /// Sets the number of documents buffered in memory before the segment is flushed.
+    pub fn set_max_buffered_docs(&mut self, max_buffered_docs: u32) {
+        self.max_buffered_docs = max_buffered_docs.max(1);
+    }
+
+    /// Returns the number of documents of the index.
+    pub fn num_docs(&self) -> u32 {
+        self.segments()
+            .iter()
+            .map(|segment| segment.num_docs())
+            .sum()
+    }
+
+    /// Returns the segments of the index, including the in-memory segment if it has documents.
+    ///
+    /// The merges finished since the last added document or flush are not applied yet.
+    pub fn segments(&self) -> Vec<&Arc<Segment>> {
+        let mut segments: Vec<&Arc<Segment>> = self.segments.iter().collect();
+        if self.buffer.num_docs() > 0 {
+            segments.push(&self.buffer);
+        }
+        segments
+    }
+
+    /// Flushes the in-memory segment, if it has documents, and starts the merges selected by the
+    /// merge policy.
+    pub fn flush(&mut self) {
+        if self.buffer.num_docs() > 0 {
+            let buffer = mem::take(&mut self.buffer);
+            self.segments.push(buffer);
+        }
+        self.apply_merges(false);
+        self.start_merges();
+    }
+
+    /// Waits for the running merges to finish, along with the merges they lead to.
+    pub fn wait_for_merges(&mut self) {
+        while !self.merges.is_empty() {
+            self.apply_merges(true);
+            self.start_merges();
+        }
+    }
+
+    /// Returns the segments of the index with the results of the finished merges, to open a
+    /// reader.
+    pub(super) fn snapshot(&self) -> Vec<Arc<Segment>> {
+        let mut segments = self.segments.clone();
+        for merge in &self.merges {
+            if let Some(merged) = merge.merged() {
+                splice(&mut segments, &merge.segments, merged);
+            }
+        }
+        if self.buffer.num_docs() > 0 {
+            segments.push(Arc::clone(&self.buffer));
+        }
+        segments
+    }
+
+    /// Returns the in-memory segment, to add a document to it.
+    ///
+    /// If the segment is shared with a reader, it is copied first so that the reader keeps
+    /// seeing the index as it was when opened, without flushing a small segment.
+    pub(super) fn buffer(&mut self) -> &mut Segment {
+        Arc::make_mut(&mut self.buffer)
+    }
+
+    /// Ends the document added to the in-memory segment, flushing it once full.
+    pub(super) fn end_doc(&mut self) {
+        self.buffer().end_doc();
+        if self.buffer.num_docs() >= self.max_buffered_docs {
+            self.flush();
+        } else if !self.merges.is_empty() {
+            self.apply_merges(false);
+            self.start_merges();
+        }
+    }
+
+    /// Replaces the merged segments with the results of the finished merges, or of all the
+    /// merges if `wait` is `true`.
+    fn apply_merges(&mut self, wait: bool) {
+        for merge in mem::take(&mut self.merges) {
+            if wait {
+                merge.handle.join().expect("could not merge the segments");
+            } else if merge.merged().is_none() {
+                self.merges.push(merge);
+                continue;
+            }
+            let merged = merge
+                .merged
+                .lock()
+                .expect("could not merge the segments")
+                .take()
+                .expect("the segments are not merged");
+            splice(&mut self.segments, &merge.segments, merged);
+        }
+    }
+
+    /// Starts the merges selected by the merge policy, among the segments not being merged.
+    fn start_merges(&mut self) {
+        let mut start = 0;
+        while start < self.segments.len() {
+            // the run of consecutive segments not being merged, from start
+            let end = (start..self.segments.len())
+                .find(|&i| self.is_merging(&self.segments[i]))
+                .unwrap_or(self.segments.len());
+            let sizes: Vec<u32> = self.segments[start..end]
+                .iter()
+                .map(|segment| segment.num_docs())
+                .collect();
+            for range in self.merge_policy.find_merges(&sizes) {
+                let segments = self.segments[start + range.start..start + range.end].to_vec();
+                let to_merge = segments.clone();
+                let merged = Arc::new(Mutex::new(None));
+                let result = Arc::clone(&merged);
+                let handle = thread::spawn(move || {
+                    let segment = Segment::merge(&to_merge);
+                    *result.lock().expect("could not merge the segments") = Some(Arc::new(segment));
+                });
+                self.merges.push(Merge {
+                    segments,
+                    handle,
+                    merged,
+                });
+            }
+            start = end + 1;
+        }
+    }
+
+    fn is_merging(&self, segment: &Arc<Segment>) -> bool {
+        self.merges.iter().any(|merge| {
+            merge
+                .segments
+                .iter()
+                .any(|merging| Arc::ptr_eq(merging, segment))
+        })
+    }
+}
+
+/// Replaces the given consecutive segments with the segment they are merged into.
+fn splice(
+    segments: &mut Vec<Arc<Segment>>,
+    merged_segments: &[Arc<Segment>],
+    merged: Arc<Segment>,
+) {
+    let start = segments
+        .iter()
+        .position(|segment| Arc::ptr_eq(segment, &merged_segments[0]))
+        .expect("could not find the merged segments");
+    let end = start + merged_segments.len();
+    segments.splice(start..end, Some(merged));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use index::merge_policy::LogSizeMergePolicy;
+    use index::posting_lists::DocItem;
+    use index::reader;
+    use tokenizer::Tokenizer;
+    use tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+
+    fn add_doc(writer: &mut IndexWriter, value: &str) {
+        let tokens = WhiteSpaceTokenizer::new().tokenize(value).collect();
+        writer.buffer().add_tokens("field1", tokens, false, false);
+        writer.end_doc();
+    }
+
+    fn segment_sizes(writer: &IndexWriter) -> Vec<u32> {
+        writer
+            .segments()
+            .iter()
+            .map(|segment| segment.num_docs())
+            .collect()
+    }
+
+    #[test]
+    fn should_flush_full_segments() {
+        let mut writer = IndexWriter::new();
+        writer.set_max_buffered_docs(2);
+        for value in &["aaa", "bbb", "aaa", "ccc", "aaa"] {
+            add_doc(&mut writer, value);
+        }
+        assert_eq!(writer.num_docs(), 5);
+        assert_eq!(segment_sizes(&writer), vec![2, 2, 1]);
+
+        writer.flush();
+        writer.flush();
+        assert_eq!(segment_sizes(&writer), vec![2, 2, 1]);
+    }
+
+    #[test]
+    fn should_copy_segments_shared_with_readers() {
+        let mut writer = IndexWriter::new();
+        add_doc(&mut writer, "aaa");
+        let snapshot = writer.snapshot();
+        add_doc(&mut writer, "aaa");
+        add_doc(&mut writer, "aaa");
+
+        assert_eq!(snapshot[0].num_docs(), 1);
+        assert_eq!(segment_sizes(&writer), vec![3]);
+    }
+
+    #[test]
+    fn should_merge_segments() {
+        let mut policy = LogSizeMergePolicy::new();
+        policy.set_merge_factor(3);
+        policy.set_min_merge_docs(1);
+        let mut writer = IndexWriter::new();
+        writer.set_merge_policy(policy);
+        writer.set_max_buffered_docs(1);
+
+        for value in &["aaa", "bbb", "aaa", "ccc", "aaa", "ddd", "aaa", "eee", "aaa", "fff"] {
+            add_doc(&mut writer, value);
+        }
+        writer.wait_for_merges();
+
+        // 9 segments of level 0, then 3 of level 1, are merged
+        assert_eq!(segment_sizes(&writer), vec![9, 1]);
+        let segments = writer.segments().into_iter().map(|segment| &**segment);
+        let docs: Vec<u32> = reader::get_postings_list(segments, "field1:aaa")
+            .iter_docs()
+            .map(|doc| doc.get_doc_id())
+            .collect();
+        assert_eq!(docs, vec![0, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn should_see_finished_merges() {
+        let mut policy = LogSizeMergePolicy::new();
+        policy.set_merge_factor(2);
+        policy.set_min_merge_docs(1);
+        let mut writer = IndexWriter::new();
+        writer.set_merge_policy(policy);
+        writer.set_max_buffered_docs(1);
+
+        add_doc(&mut writer, "aaa");
+        add_doc(&mut writer, "bbb");
+        while writer.merges.iter().any(|merge| merge.merged().is_none()) {
+            thread::yield_now();
+        }
+
+        // the finished merge is seen by readers before it is applied
+        assert_eq!(segment_sizes(&writer), vec![1, 1]);
+        let sizes: Vec<u32> = writer
+            .snapshot()
+            .iter()
+            .map(|segment| segment.num_docs())
+            .collect();
+        assert_eq!(sizes, vec![2]);
+
+        writer.set_max_buffered_docs(10);
+        add_doc(&mut writer, "ccc");
+        assert_eq!(segment_sizes(&writer), vec![2, 1]);
+    }
+}
@@ -1,6 +1,14 @@
 extern crate failure;
 #[macro_use]
 extern crate failure_derive;
+extern crate regex;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate serde_path_to_error;
+extern crate unicode_normalization;
+extern crate unicode_segmentation;
 
 #[cfg(test)]
 #[macro_use(expect)]
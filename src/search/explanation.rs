@@ -0,0 +1,105 @@
+//! Describe why a document matches a query or not.
+//!
+//! An [`Explanation`] is returned by [`IndexSearcher::explain`][explain]. It is a tree mirroring
+//! the structure of the query, where each node tells if that part of the query matched the
+//! document, at which positions, and with which term statistics.
+//!
+//! The searches are not scored, their hits are returned by document ID, so the values of an
+//! explanation rank nothing: they are a diagnostic breakdown of the term statistics, weighting
+//! the rare terms more than the common ones:
+//! - a term weighs its frequency in the document multiplied by its [`Explanation::term_weight`],
+//!   computed from its document frequency as `1 + ln(num_docs / (doc_freq + 1))`;
+//! - a phrase weighs the sum of the weights of its terms;
+//! - a boolean query weighs the sum of its `must` clauses, and `0` if a `must_not` clause
+//!   matched.
+//!
+//! [explain]: ../struct.IndexSearcher.html#method.explain
+use std::fmt;
+
+/// A node of the explanation tree.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Explanation {
+    /// `true` if that part of the query matched the document.
+    pub matched: bool,
+    /// The weight of that part of the query, see the [module documentation](index.html).
+    pub value: f32,
+    /// What that part of the query is about.
+    pub description: String,
+    /// The positions in the document where that part of the query matched.
+    pub positions: Vec<u32>,
+    /// The explanations this one is computed from.
+    pub details: Vec<Explanation>,
+}
+
+impl Explanation {
+    /// Creates an explanation for a part of the query that matched the document.
+    pub fn matched(value: f32, description: String) -> Explanation {
+        Explanation {
+            matched: true,
+            value,
+            description,
+            positions: Vec::new(),
+            details: Vec::new(),
+        }
+    }
+
+    /// Creates an explanation for a part of the query that did not match the document.
+    pub fn no_match(description: String) -> Explanation {
+        Explanation {
+            matched: false,
+            value: 0.0,
+            description,
+            positions: Vec::new(),
+            details: Vec::new(),
+        }
+    }
+
+    /// Returns the weight of a term occurring in `doc_freq` documents out of `num_docs`, i.e.,
+    /// its inverse document frequency.
+    pub fn term_weight(doc_freq: usize, num_docs: u32) -> f32 {
+        1.0 + (num_docs as f32 / (doc_freq as f32 + 1.0)).ln()
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter, depth: usize) -> fmt::Result {
+        write!(f, "{:width$}{} = {}", "", self.value, self.description, width = depth * 2)?;
+        if !self.matched {
+            write!(f, " (no match)")?;
+        }
+        if !self.positions.is_empty() {
+            write!(f, " at positions {:?}", self.positions)?;
+        }
+        writeln!(f)?;
+        for detail in &self.details {
+            detail.fmt_indented(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Prints the explanation tree, one node per line.
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let mut term = Explanation::matched(2.0, String::from("term field1:aaa"));
+        term.positions = vec![1, 3];
+        let mut bq = Explanation::matched(2.0, String::from("boolean query, sum of:"));
+        bq.details.push(term);
+        bq.details
+            .push(Explanation::no_match(String::from("must_not: term field1:bbb")));
+
+        assert_eq!(
+            bq.to_string(),
+            "2 = boolean query, sum of:\n  2 = term field1:aaa at positions [1, 3]\n  0 = \
+             must_not: term field1:bbb (no match)\n"
+        );
+    }
+}
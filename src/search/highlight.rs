@@ -0,0 +1,460 @@
+//! Highlight the terms of a query within the value of a field.
+//!
+//! The [`Highlighter`] re-tokenizes the value with the [`Tokenizer`][tokenizer] mapped to the
+//! field, so that the terms of the query are found as they were indexed, and relies on the offsets
+//! of the [`Token`]s to locate them. The value is then cut into fragments,
+//! and the fragments with the most distinct matching terms are returned with those terms wrapped
+//! in a pair of tags.
+//!
+//! Only true phrase matches of a [`PhraseQuery`][phrase] are highlighted, and the `must_not`
+//! clauses of a [`BooleanQuery`][boolean] are ignored.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::index::Index;
+//! use ::iryfful::search::highlight::Highlighter;
+//! use ::iryfful::search::query::phrase_query::PhraseQuery;
+//! use ::iryfful::tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+//!
+//! let mut index: Index = Default::default();
+//! index.set_mapping(String::from("field1"), WhiteSpaceTokenizer::new())
+//!     .unwrap();
+//!
+//! let mut highlighter = Highlighter::new(&index);
+//! highlighter.set_tags("[", "]");
+//!
+//! let pq = PhraseQuery::new("field1", vec!["aaa", "bbb"]);
+//! let fragments = highlighter.highlight(&pq, "field1", "aaa ccc bbb ddd aaa bbb").unwrap();
+//!
+//! assert_eq!(fragments[0].text, "aaa ccc bbb ddd [aaa] [bbb]");
+//! ```
+//!
+//! [tokenizer]: ../../tokenizer/trait.Tokenizer.html
+//! [phrase]: ../query/phrase_query/index.html
+//! [boolean]: ../query/boolean_query/index.html
+use index::Index;
+use index::error::IndexingError;
+use search::query::Query;
+use search::query::dsl::QueryDsl;
+use search::query::phrase_query;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use tokenizer::Token;
+
+/// A part of a field's value with the matching terms highlighted.
+#[derive(Debug, PartialEq)]
+pub struct Fragment {
+    /// The highlighted text.
+    pub text: String,
+    /// The number of distinct terms of the query matching in this fragment.
+    pub score: usize,
+}
+
+/// The `Highlighter` type creates [`Fragment`]s of a field's value matching a query.
+pub struct Highlighter<'a> {
+    index: &'a Index<'a>,
+    pre_tag: String,
+    post_tag: String,
+    fragment_size: usize,
+    max_fragments: usize,
+}
+
+impl<'a> Highlighter<'a> {
+    /// Creates a new highlighter using the mappings of the given index.
+    ///
+    /// By default, matching terms are wrapped in `<em>` tags, and at most 3 fragments of about 100
+    /// characters are returned.
+    pub fn new(index: &'a Index<'a>) -> Highlighter<'a> {
+        Highlighter {
+            index,
+            pre_tag: String::from("<em>"),
+            post_tag: String::from("</em>"),
+            fragment_size: 100,
+            max_fragments: 3,
+        }
+    }
+
+    /// Sets the tags inserted before and after a matching term.
+    pub fn set_tags(&mut self, pre_tag: &str, post_tag: &str) {
+        self.pre_tag = pre_tag.to_string();
+        self.post_tag = post_tag.to_string();
+    }
+
+    /// Sets the number of characters after which a fragment is cut.
+    ///
+    /// A fragment is only cut in between tokens, so it may be longer if a single token exceeds
+    /// that size.
+    pub fn set_fragment_size(&mut self, fragment_size: usize) {
+        self.fragment_size = fragment_size;
+    }
+
+    /// Sets the maximum number of fragments to return.
+    pub fn set_max_fragments(&mut self, max_fragments: usize) {
+        self.max_fragments = max_fragments;
+    }
+
+    /// Returns the best fragments of the value of the specified field matching the query, sorted
+    /// by decreasing score.
+    ///
+    /// # Errors
+    ///
+    /// An [`IndexingError::MissingFieldMapping`] error is returned if the field has no mapping
+    /// defined.
+    pub fn highlight<T>(
+        &self,
+        query: &T,
+        field: &str,
+        value: &str,
+    ) -> Result<Vec<Fragment>, IndexingError>
+    where
+        T: Query + ?Sized,
+    {
+        let tokenizer = match self.index.get_tokenizer(field) {
+            None => {
+                return Err(IndexingError::MissingFieldMapping {
+                    field: field.to_string(),
+                })
+            }
+            Some(tokenizer) => tokenizer,
+        };
+        let tokens: Vec<Token> = tokenizer.tokenize(value).collect();
+
+        let mut highlighted = HashSet::new();
+        collect_positions(
+            self.index,
+            &query.to_dsl(),
+            field,
+            &tokens,
+            &mut highlighted,
+        );
+        if highlighted.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut fragments = Vec::new();
+        let mut first = 0;
+        for (i, token) in tokens.iter().enumerate() {
+            let start = tokens[first].start_offset as usize;
+            let end = token.end_offset as usize;
+            if i > first && value[start..end].chars().count() > self.fragment_size {
+                fragments.push(self.fragment(value, &tokens[first..i], &highlighted));
+                first = i;
+            }
+        }
+        fragments.push(self.fragment(value, &tokens[first..], &highlighted));
+
+        let mut fragments: Vec<(usize, Fragment)> = fragments.into_iter().flatten().collect();
+        // the stable sort keeps fragments with equal scores in order of appearance
+        fragments.sort_by_key(|&(count, ref fragment)| Reverse((fragment.score, count)));
+        Ok(fragments
+            .into_iter()
+            .take(self.max_fragments)
+            .map(|(_, fragment)| fragment)
+            .collect())
+    }
+
+    /// Returns the fragment spanning the given tokens along with its number of highlighted
+    /// tokens, or `None` if none are.
+    fn fragment(
+        &self,
+        value: &str,
+        tokens: &[Token],
+        highlighted: &HashSet<u32>,
+    ) -> Option<(usize, Fragment)> {
+        let mut text = String::new();
+        let mut terms = HashSet::new();
+        let mut count = 0;
+        let mut last = tokens[0].start_offset as usize;
+        for token in tokens {
+            if !highlighted.contains(&token.position) {
+                continue;
+            }
+            let (start, end) = (token.start_offset as usize, token.end_offset as usize);
+            // a token stacked on a highlighted one, e.g., a part of a word, is already highlighted
+            if start < last {
+                continue;
+            }
+            text.push_str(&value[last..start]);
+            text.push_str(&self.pre_tag);
+            text.push_str(&value[start..end]);
+            text.push_str(&self.post_tag);
+            last = end;
+            terms.insert(&token.token);
+            count += 1;
+        }
+        if count == 0 {
+            return None;
+        }
+        // the last token may be stacked within a highlighted one, e.g., a part of a word
+        let end = tokens.iter().map(|token| token.end_offset as usize).max();
+        text.push_str(&value[last..end.map_or(last, |end| end.max(last))]);
+        Some((
+            count,
+            Fragment {
+                text,
+                score: terms.len(),
+            },
+        ))
+    }
+}
+
+/// Adds to `highlighted` the positions of the tokens matching the query.
+fn collect_positions(
+    index: &Index,
+    dsl: &QueryDsl,
+    field: &str,
+    tokens: &[Token],
+    highlighted: &mut HashSet<u32>,
+) {
+    match *dsl {
+        QueryDsl::Term {
+            field: ref term_field,
+            ref term,
+        } if term_field == field =>
+        {
+            let term = index.normalize(field, term);
+            highlighted.extend(
+                tokens
+                    .iter()
+                    .filter(|token| token.token == term)
+                    .map(|token| token.position),
+            );
+        }
+        QueryDsl::Phrase {
+            field: ref phrase_field,
+            ref terms,
+            slop,
+            shingles,
+        } if phrase_field == field =>
+        {
+            // the terms are looked up as by the PhraseQuery, e.g., their shingles
+            let tokenizer = index.get_tokenizer(field);
+            let terms = phrase_query::lookup_terms(tokenizer, terms, slop, shingles);
+            let mut spans = HashMap::new();
+            let terms_positions: Vec<Vec<u32>> = terms
+                .iter()
+                .map(|term| {
+                    tokens
+                        .iter()
+                        .filter(|token| token.token == *term)
+                        .map(|token| {
+                            let span = spans.entry(token.position).or_insert(1);
+                            *span = token.position_length.max(*span);
+                            token.position
+                        })
+                        .collect()
+                })
+                .collect();
+            if terms_positions.iter().any(|positions| positions.is_empty()) {
+                return;
+            }
+
+            // look for a phrase around each occurrence of every term
+            let mut positions = Vec::with_capacity(terms.len());
+            for (i, term_positions) in terms_positions.iter().enumerate() {
+                for pos in term_positions.iter() {
+                    let mut candidates: Vec<&[u32]> = Vec::with_capacity(terms.len());
+                    candidates.push(::std::slice::from_ref(pos));
+                    candidates.extend(
+                        terms_positions
+                            .iter()
+                            .enumerate()
+                            .filter(|&(j, _)| j != i)
+                            .map(|(_, positions)| &positions[..]),
+                    );
+                    if phrase_query::match_positions(slop, &candidates, &mut positions) {
+                        // a shingle spans the positions of its words
+                        for position in positions.iter() {
+                            highlighted.extend(*position..*position + spans[position]);
+                        }
+                    }
+                }
+            }
+        }
+        QueryDsl::Bool { ref must, .. } => for query in must {
+            collect_positions(index, query, field, tokens, highlighted);
+        },
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use index::document::Document;
+    use search::{IndexSearcher, SearchHit};
+    use search::query::boolean_query::BooleanQuery;
+    use search::query::phrase_query::PhraseQuery;
+    use search::query::term_query::TermQuery;
+    use tokenizer::Tokenizer;
+    use tokenizer::char_filter::CharFilter;
+    use tokenizer::filter::TokenFilter;
+    use tokenizer::filter::shingles::Shingles;
+    use tokenizer::filter::word_delimiter::WordDelimiter;
+    use tokenizer::standard_tokenizer::StandardTokenizer;
+    use tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+
+    fn new_index<'a>() -> Index<'a> {
+        let mut index: Index = Default::default();
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::LowerCase);
+        index
+            .set_mapping(String::from("field1"), tokenizer)
+            .unwrap();
+        index
+    }
+
+    #[test]
+    fn test_highlight_terms() {
+        let index = new_index();
+        let highlighter = Highlighter::new(&index);
+
+        let tq = TermQuery::new("field1", "aaa");
+        let fragments = highlighter
+            .highlight(&tq, "field1", "AAA bbb\taaa ccc")
+            .unwrap();
+
+        assert_eq!(
+            fragments,
+            vec![Fragment {
+                text: String::from("<em>AAA</em> bbb\t<em>aaa</em> ccc"),
+                score: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_highlight_html() {
+        let mut index: Index = Default::default();
+        let mut tokenizer = StandardTokenizer::new();
+        tokenizer.add_char_filter(CharFilter::HtmlStrip);
+        tokenizer.add_filter(TokenFilter::LowerCase);
+        index.set_mapping(String::from("field1"), tokenizer).unwrap();
+        let mut highlighter = Highlighter::new(&index);
+        highlighter.set_tags("[", "]");
+
+        let tq = TermQuery::new("field1", "chips");
+        let fragments = highlighter
+            .highlight(&tq, "field1", "<p>Fish &amp; <b>Chips</b></p>")
+            .unwrap();
+
+        assert_eq!(fragments.len(), 1);
+        assert_eq!(fragments[0].text, "Fish &amp; <b>[Chips]");
+    }
+
+    #[test]
+    fn test_highlight_only_phrase_matches() {
+        let index = new_index();
+        let mut highlighter = Highlighter::new(&index);
+        highlighter.set_tags("[", "]");
+
+        let mut pq = PhraseQuery::new("field1", vec!["aaa", "bbb"]);
+        pq.set_slop(2);
+        let fragments = highlighter
+            .highlight(&pq, "field1", "aaa ccc ddd bbb aaa ccc bbb")
+            .unwrap();
+
+        assert_eq!(fragments.len(), 1);
+        assert_eq!(fragments[0].text, "aaa ccc ddd [bbb] [aaa] ccc [bbb]");
+
+        // no phrase match
+        let fragments = highlighter
+            .highlight(&pq, "field1", "aaa ccc ddd bbb")
+            .unwrap();
+        assert!(fragments.is_empty());
+    }
+
+    #[test]
+    fn test_highlight_shingled_phrases() {
+        let mut index: Index = Default::default();
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::Shingles(Shingles::new(2, 2)));
+        index.set_mapping(String::from("field1"), tokenizer).unwrap();
+        let mut doc: Document = Default::default();
+        for value in &["bbb aaa ccc", "bbb aaa ccc aaa bbb"] {
+            doc.clear();
+            doc.add_field("field1", value);
+            index.add_doc(&doc).unwrap();
+        }
+        let mut highlighter = Highlighter::new(&index);
+        highlighter.set_tags("[", "]");
+
+        // the shingles match the terms in order only, as when searching
+        let mut pq = PhraseQuery::new("field1", vec!["aaa", "bbb"]);
+        pq.set_shingles(true);
+        let hits: Vec<SearchHit> = pq.execute(&IndexSearcher::new(&index)).collect();
+        assert_eq!(hits, vec![SearchHit::new(1)]);
+
+        let fragments = highlighter
+            .highlight(&pq, "field1", "bbb aaa ccc")
+            .unwrap();
+        assert!(fragments.is_empty());
+        let fragments = highlighter
+            .highlight(&pq, "field1", "bbb aaa ccc aaa bbb")
+            .unwrap();
+        assert_eq!(fragments.len(), 1);
+        assert_eq!(fragments[0].text, "bbb aaa ccc [aaa] [bbb]");
+    }
+
+    #[test]
+    fn test_best_fragments() {
+        let index = new_index();
+        let mut highlighter = Highlighter::new(&index);
+        highlighter.set_tags("[", "]");
+        highlighter.set_fragment_size(11);
+        highlighter.set_max_fragments(2);
+
+        let mut bq: BooleanQuery = Default::default();
+        bq.must(TermQuery::new("field1", "aaa"));
+        bq.must(TermQuery::new("field1", "bbb"));
+        bq.must_not(TermQuery::new("field1", "ccc"));
+        let fragments = highlighter
+            .highlight(&bq, "field1", "aaa ddd eee ccc bbb ddd aaa bbb aaa ddd ccc")
+            .unwrap();
+
+        assert_eq!(
+            fragments,
+            vec![
+                Fragment {
+                    text: String::from("[aaa] [bbb] [aaa]"),
+                    score: 2,
+                },
+                Fragment {
+                    text: String::from("[aaa] ddd eee"),
+                    score: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stacked_tokens_at_fragment_boundary() {
+        let mut index: Index = Default::default();
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::WordDelimiter(WordDelimiter {
+            preserve_original: true,
+            ..Default::default()
+        }));
+        index.set_mapping(String::from("field1"), tokenizer).unwrap();
+        let mut highlighter = Highlighter::new(&index);
+        highlighter.set_tags("[", "]");
+        highlighter.set_fragment_size(4);
+
+        // the first fragment ends with the part wi, stacked within the original word
+        let tq = TermQuery::new("field1", "wi");
+        let fragments = highlighter.highlight(&tq, "field1", "wi-fi").unwrap();
+
+        assert_eq!(fragments.len(), 1);
+        assert_eq!(fragments[0].text, "[wi-fi]");
+    }
+
+    #[test]
+    fn test_missing_mapping() {
+        let index = new_index();
+        let highlighter = Highlighter::new(&index);
+
+        let tq = TermQuery::new("field2", "aaa");
+        assert!(highlighter.highlight(&tq, "field2", "aaa").is_err());
+    }
+}
@@ -1,12 +1,18 @@
 //! Execute queries over an index and retrieve matching documents.
 //!
 //! An [`Index`] is passed to the [`IndexSearcher`] immutably and [`query::Query`]s can be executed
-//! thanks to the [`IndexSearcher::search`] method.
+//! thanks to the [`IndexSearcher::search`] method. The searcher operates on an
+//! [`IndexReader`], so that documents can be added to the index meanwhile. The reader can be sent
+//! to other threads, e.g., to search the index concurrently.
 use index::Index;
 use index::posting_lists::DocItem;
+use index::reader::IndexReader;
+use search::explanation::Explanation;
 use std::mem;
 use std::u32::MAX;
 
+pub mod explanation;
+pub mod highlight;
 pub mod query;
 
 /// A SearchHit references a document that is a match for a query.
@@ -32,18 +38,23 @@ impl DocItem for SearchHit {
 
 /// The `IndexSearcher` type provides an API for executing [`query::Query`]s over an index.
 pub struct IndexSearcher<'a> {
-    index: &'a Index<'a>,
+    reader: IndexReader<'a>,
 }
 
 impl<'q, 'a: 'q> IndexSearcher<'a> {
-    /// Creates a new IndexSearcher instance over an index.
-    pub fn new(index: &'a Index<'a>) -> IndexSearcher<'a> {
-        IndexSearcher { index }
+    /// Creates a new IndexSearcher instance over the documents added so far to an index.
+    pub fn new(index: &Index<'a>) -> IndexSearcher<'a> {
+        IndexSearcher::from_reader(index.reader())
     }
 
-    /// Returns the index this searcher operates on.
-    fn get_index(&self) -> &Index<'a> {
-        self.index
+    /// Creates a new IndexSearcher instance over an index reader.
+    pub fn from_reader(reader: IndexReader<'a>) -> IndexSearcher<'a> {
+        IndexSearcher { reader }
+    }
+
+    /// Returns the reader this searcher operates on.
+    pub fn get_reader(&self) -> &IndexReader<'a> {
+        &self.reader
     }
 
     /// Execute a query over the index and returns a list of hits.
@@ -100,6 +111,45 @@ impl<'q, 'a: 'q> IndexSearcher<'a> {
         Box::new(query.execute(self))
     }
 
+    /// Explains why the document with the given ID matches the query or not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ::iryfful::search::IndexSearcher;
+    /// use ::iryfful::search::query::boolean_query::BooleanQuery;
+    /// use ::iryfful::search::query::term_query::TermQuery;
+    /// use ::iryfful::index::document::Document;
+    /// use ::iryfful::index::Index;
+    /// use ::iryfful::tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+    ///
+    /// let mut index: Index = Default::default();
+    /// index.set_mapping(String::from("field1"), WhiteSpaceTokenizer::new())
+    ///     .unwrap();
+    ///
+    /// let mut doc: Document = Default::default();
+    /// doc.add_field("field1", "aaa bbb aaa");
+    /// index.add_doc(&doc).unwrap();
+    ///
+    /// let mut bq: BooleanQuery = Default::default();
+    /// bq.must(TermQuery::new("field1", "aaa"));
+    /// bq.must_not(TermQuery::new("field1", "bbb"));
+    ///
+    /// let index_search = IndexSearcher::new(&index);
+    /// let explanation = index_search.explain(&bq, 0);
+    ///
+    /// // the document is excluded by the must_not clause
+    /// assert!(!explanation.matched);
+    /// assert!(explanation.details[1].matched);
+    /// assert_eq!(explanation.details[1].positions, vec![2]);
+    /// ```
+    pub fn explain<T>(&self, query: &T, doc_id: u32) -> Explanation
+    where
+        T: query::Query,
+    {
+        query.explain(self, doc_id)
+    }
+
     /// Iterates over a list of [`Iterator`]s over [`DocItem`]s and returns another Iterator which
     /// items are those which [`DocItem::get_doc_id`] match.
     fn conjunction<I, T>(&self, docs: Vec<Box<I>>) -> ConjunctionDocIterator<I, T>
@@ -281,6 +331,9 @@ mod tests {
     use index::posting_lists;
     use index::posting_lists::DocIdAndPosItem;
     use index::posting_lists::Posting;
+    use search::query::Query;
+    use search::query::term_query::TermQuery;
+    use std::thread;
     use tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
 
     #[test]
@@ -601,4 +654,28 @@ mod tests {
         assert_eq!(iter.next().unwrap().get_doc_id(), 3);
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn test_search_in_another_thread() {
+        let mut index: Index = Default::default();
+        index
+            .set_mapping(String::from("field1"), WhiteSpaceTokenizer::new())
+            .unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("field1", "aaa bbb");
+        index.add_doc(&doc).unwrap();
+
+        let reader = index.reader();
+        let handle = thread::spawn(move || {
+            let searcher = IndexSearcher::from_reader(reader);
+            TermQuery::new("field1", "aaa").execute(&searcher).count()
+        });
+
+        doc.clear();
+        doc.add_field("field1", "aaa");
+        index.add_doc(&doc).unwrap();
+
+        assert_eq!(handle.join().unwrap(), 1);
+    }
 }
@@ -1,7 +1,7 @@
 //! Match a document that fulfils a boolean combination of queries
 //!
 //! The `must` clause defines queries that must match a document. It is added thanks to
-//! [`BooleanQuery::must`] method.
+//! [`BooleanQuery::must`] method. A query without `must` clause matches no document.
 //!
 //! # Examples
 //!
@@ -17,9 +17,12 @@
 //! ```
 use super::Query;
 use super::SearchHit;
+use super::dsl::QueryDsl;
 use index::posting_lists::DocItem;
 use search::DocIterator;
 use search::IndexSearcher;
+use search::explanation::Explanation;
+use std::iter;
 
 #[derive(Debug, Default)]
 pub struct BooleanQuery<'bq> {
@@ -50,6 +53,9 @@ impl<'bq> Query for BooleanQuery<'bq> {
         &'q self,
         index_search: &'i IndexSearcher,
     ) -> Box<Iterator<Item = SearchHit> + 'q> {
+        if self.must.is_empty() {
+            return Box::new(iter::empty());
+        }
         let must_results = self.must
             .iter()
             .map(|query| Box::new(query.execute(index_search)))
@@ -99,6 +105,41 @@ impl<'bq> Query for BooleanQuery<'bq> {
                 }),
         )
     }
+
+    fn to_dsl(&self) -> QueryDsl {
+        QueryDsl::Bool {
+            must: self.must.iter().map(|query| query.to_dsl()).collect(),
+            must_not: self.must_not.iter().map(|query| query.to_dsl()).collect(),
+        }
+    }
+
+    fn explain(&self, index_search: &IndexSearcher, doc_id: u32) -> Explanation {
+        let mut matched = !self.must.is_empty();
+        let mut value = 0.0;
+        let mut details = Vec::with_capacity(self.must.len() + self.must_not.len());
+        for query in self.must.iter() {
+            let mut detail = query.explain(index_search, doc_id);
+            matched = matched && detail.matched;
+            value += detail.value;
+            detail.description = format!("must: {}", detail.description);
+            details.push(detail);
+        }
+        for query in self.must_not.iter() {
+            let mut detail = query.explain(index_search, doc_id);
+            // a matching must_not clause excludes the document
+            matched = matched && !detail.matched;
+            detail.description = format!("must_not: {}", detail.description);
+            details.push(detail);
+        }
+
+        let mut explanation = if matched {
+            Explanation::matched(value, String::from("boolean query, sum of must clauses:"))
+        } else {
+            Explanation::no_match(String::from("boolean query"))
+        };
+        explanation.details = details;
+        explanation
+    }
 }
 
 #[cfg(test)]
@@ -459,4 +500,44 @@ mod tests {
         let next_doc = iter.next();
         assert_eq!(next_doc, None);
     }
+
+    #[test]
+    fn test_explain() {
+        let mut index: Index = Default::default();
+        index
+            .set_mapping(String::from("field1"), WhiteSpaceTokenizer::new())
+            .unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("field1", "aaa bbb ccc");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("field1", "aaa ccc");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = IndexSearcher::new(&index);
+
+        let mut bq: BooleanQuery = Default::default();
+        bq.must(TermQuery::new("field1", "aaa"));
+        bq.must(TermQuery::new("field1", "ccc"));
+        bq.must_not(PhraseQuery::new("field1", vec!["aaa", "bbb"]));
+
+        // excluded by the must_not clause
+        let explanation = bq.explain(&index_search, 0);
+        assert!(!explanation.matched);
+        assert_eq!(explanation.value, 0.0);
+        assert_eq!(explanation.details.len(), 3);
+        assert!(explanation.details[2].matched);
+        assert_eq!(explanation.details[2].positions, vec![1, 2]);
+        assert!(explanation.details[2].description.starts_with("must_not: phrase"));
+
+        let explanation = bq.explain(&index_search, 1);
+        assert!(explanation.matched);
+        assert!(!explanation.details[2].matched);
+        assert_eq!(
+            explanation.value,
+            explanation.details[0].value + explanation.details[1].value
+        );
+    }
 }
@@ -0,0 +1,388 @@
+//! Describe queries in JSON.
+//!
+//! Each [`Query`] type has a JSON representation, where the name of the query is the key of an
+//! object holding its parameters:
+//! - a term query: `{"term": {"field": "field1", "term": "aaa"}}`
+//! - a phrase query: `{"phrase": {"field": "field1", "terms": ["aaa", "bbb"], "slop": 2}}`, where
+//!   the `slop` is optional and defaults to `1`, and `shingles` is an optional flag, see
+//!   [`PhraseQuery::set_shingles`].
+//! - a boolean query: `{"bool": {"must": [...], "must_not": [...]}}`, where both clauses are
+//!   optional, see [`BooleanQuery`].
+//!
+//! The path of the offending element is reported in the [`QueryError`] returned when the JSON
+//! document is not a valid query, e.g., `bool.must[1].phrase.terms`. The path of the root of the
+//! document is empty, e.g., for an unknown query type.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::search::query::dsl;
+//!
+//! let query = dsl::from_json(r#"{
+//!     "bool": {
+//!         "must": [{"phrase": {"field": "field1", "terms": ["aaa", "bbb"]}}],
+//!         "must_not": [{"term": {"field": "field1", "term": "ccc"}}]
+//!     }
+//! }"#).unwrap();
+//!
+//! // the query tree can be serialized back, e.g., for logging it
+//! assert_eq!(
+//!     dsl::to_json(&*query),
+//!     r#"{"bool":{"must":[{"phrase":{"field":"field1","terms":["aaa","bbb"],"slop":1}}],"must_not":[{"term":{"field":"field1","term":"ccc"}}]}}"#
+//! );
+//! ```
+use super::Query;
+use super::boolean_query::BooleanQuery;
+use super::error::QueryError;
+use super::phrase_query::PhraseQuery;
+use super::term_query::TermQuery;
+use serde_json;
+use serde_path_to_error;
+
+type QueryResult<T> = Result<T, QueryError>;
+
+/// The serializable representation of a [`Query`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum QueryDsl {
+    /// A [`TermQuery`].
+    Term { field: String, term: String },
+    /// A [`PhraseQuery`].
+    Phrase {
+        field: String,
+        terms: Vec<String>,
+        #[serde(default = "default_slop")]
+        slop: u8,
+        /// See [`PhraseQuery::set_shingles`]
+        #[serde(default, skip_serializing_if = "is_false")]
+        shingles: bool,
+    },
+    /// A [`BooleanQuery`].
+    Bool {
+        #[serde(default)]
+        must: Vec<QueryDsl>,
+        #[serde(default)]
+        must_not: Vec<QueryDsl>,
+    },
+}
+
+fn default_slop() -> u8 {
+    1
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+impl QueryDsl {
+    /// Checks that this query can be executed.
+    ///
+    /// # Errors
+    ///
+    /// An [`QueryError::InvalidQuery`] error is returned with the path of the first invalid
+    /// element, prefixed with the given path, which is empty for the root of a document.
+    pub fn validate(&self, path: &str) -> QueryResult<()> {
+        match *self {
+            QueryDsl::Term {
+                ref field,
+                ref term,
+            } => {
+                let path = join(path, "term");
+                check_not_empty(&path, "field", field)?;
+                check_not_empty(&path, "term", term)
+            }
+            QueryDsl::Phrase {
+                ref field,
+                ref terms,
+                slop,
+                ..
+            } => {
+                let path = join(path, "phrase");
+                check_not_empty(&path, "field", field)?;
+                if terms.len() < 2 {
+                    return Err(invalid(
+                        join(&path, "terms"),
+                        "a phrase requires at least two terms",
+                    ));
+                }
+                for (i, term) in terms.iter().enumerate() {
+                    check_not_empty(&path, &format!("terms[{}]", i), term)?;
+                }
+                if slop == 0 {
+                    return Err(invalid(join(&path, "slop"), "the slop must be at least 1"));
+                }
+                Ok(())
+            }
+            QueryDsl::Bool {
+                ref must,
+                ref must_not,
+            } => {
+                let path = join(path, "bool");
+                for (i, query) in must.iter().enumerate() {
+                    query.validate(&join(&path, &format!("must[{}]", i)))?;
+                }
+                for (i, query) in must_not.iter().enumerate() {
+                    query.validate(&join(&path, &format!("must_not[{}]", i)))?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Creates the [`Query`] described by this representation.
+    pub fn into_query(self) -> Box<Query> {
+        match self {
+            QueryDsl::Term { field, term } => Box::new(TermQuery::new(field, term)),
+            QueryDsl::Phrase {
+                field,
+                terms,
+                slop,
+                shingles,
+            } => {
+                let mut pq = PhraseQuery::new(field, terms);
+                pq.set_slop(slop);
+                pq.set_shingles(shingles);
+                Box::new(pq)
+            }
+            QueryDsl::Bool { must, must_not } => {
+                let mut bq: BooleanQuery = Default::default();
+                for query in must {
+                    bq.must(query.into_query());
+                }
+                for query in must_not {
+                    bq.must_not(query.into_query());
+                }
+                Box::new(bq)
+            }
+        }
+    }
+}
+
+/// Parses and validates the JSON representation of a query.
+///
+/// # Errors
+///
+/// An [`QueryError::InvalidQuery`] error is returned if the JSON document is malformed, does not
+/// describe a query or if the query is not valid.
+pub fn parse_json(json: &str) -> QueryResult<QueryDsl> {
+    let deserializer = &mut serde_json::Deserializer::from_str(json);
+    let dsl: QueryDsl = serde_path_to_error::deserialize(deserializer)
+        .map_err(|err| invalid(path_of(err.path()), &err.inner().to_string()))?;
+    dsl.validate("")?;
+    Ok(dsl)
+}
+
+/// Creates the [`Query`] described by the given JSON document.
+///
+/// # Errors
+///
+/// See [`parse_json`].
+pub fn from_json(json: &str) -> QueryResult<Box<Query>> {
+    Ok(parse_json(json)?.into_query())
+}
+
+/// Returns the JSON representation of the given query.
+pub fn to_json(query: &Query) -> String {
+    serde_json::to_string(query).expect("a query can always be serialized")
+}
+
+/// Returns the given path as reported in errors, i.e., empty for the root rather than `.`.
+fn path_of(path: &serde_path_to_error::Path) -> String {
+    if path.iter().next().is_none() {
+        String::new()
+    } else {
+        path.to_string()
+    }
+}
+
+fn join(path: &str, element: &str) -> String {
+    if path.is_empty() {
+        element.to_string()
+    } else {
+        format!("{}.{}", path, element)
+    }
+}
+
+fn check_not_empty(path: &str, element: &str, value: &str) -> QueryResult<()> {
+    if value.is_empty() {
+        Err(invalid(join(path, element), "must not be empty"))
+    } else {
+        Ok(())
+    }
+}
+
+fn invalid(path: String, reason: &str) -> QueryError {
+    QueryError::InvalidQuery {
+        path,
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use index::Index;
+    use index::document::Document;
+    use search::IndexSearcher;
+    use search::SearchHit;
+    use tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+
+    fn assert_invalid(json: &str, expected_path: &str) {
+        match parse_json(json) {
+            Err(QueryError::InvalidQuery { path, .. }) => assert_eq!(path, expected_path),
+            Ok(dsl) => panic!("expected an error at {}, got {:?}", expected_path, dsl),
+        }
+    }
+
+    #[test]
+    fn test_parse_queries() {
+        let dsl = parse_json(
+            r#"{"bool": {
+                "must": [
+                    {"term": {"field": "field1", "term": "aaa"}},
+                    {"phrase": {"field": "field1", "terms": ["bbb", "ccc"], "slop": 3}}
+                ],
+                "must_not": [{"phrase": {"field": "field1", "terms": ["ddd", "eee"]}}]
+            }}"#,
+        ).unwrap();
+
+        assert_eq!(
+            dsl,
+            QueryDsl::Bool {
+                must: vec![
+                    QueryDsl::Term {
+                        field: String::from("field1"),
+                        term: String::from("aaa"),
+                    },
+                    QueryDsl::Phrase {
+                        field: String::from("field1"),
+                        terms: vec![String::from("bbb"), String::from("ccc")],
+                        slop: 3,
+                        shingles: false,
+                    },
+                ],
+                must_not: vec![QueryDsl::Phrase {
+                    field: String::from("field1"),
+                    terms: vec![String::from("ddd"), String::from("eee")],
+                    slop: 1,
+                    shingles: false,
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let json = r#"{"bool":{"must":[{"term":{"field":"field1","term":"aaa"}},{"bool":{"must":[{"phrase":{"field":"field2","terms":["bbb","ccc"],"slop":2}}],"must_not":[]}}],"must_not":[{"term":{"field":"field1","term":"ddd"}}]}}"#;
+
+        let query = from_json(json).unwrap();
+        assert_eq!(to_json(&*query), json);
+        assert_eq!(query.to_dsl(), parse_json(json).unwrap());
+    }
+
+    #[test]
+    fn test_round_trip_all_queries() {
+        let mut pq = PhraseQuery::new("field1", vec!["aaa", "bbb"]);
+        pq.set_slop(3);
+        pq.set_shingles(true);
+        let mut must_not: BooleanQuery = Default::default();
+        must_not.must_not(TermQuery::new("field1", "aaa"));
+        let mut nested: BooleanQuery = Default::default();
+        nested.must(PhraseQuery::new("field1", vec!["aaa", "bbb"]));
+        nested.must(BooleanQuery::default());
+        nested.must_not(must_not);
+
+        let queries: Vec<Box<Query>> = vec![
+            Box::new(TermQuery::new("field1", "aaa")),
+            Box::new(pq),
+            Box::new(BooleanQuery::default()),
+            Box::new(nested),
+        ];
+        for query in queries {
+            let dsl = query.to_dsl();
+            let json = to_json(&*query);
+            assert_eq!(parse_json(&json).unwrap(), dsl, "parsing {}", json);
+            assert_eq!(to_json(&*from_json(&json).unwrap()), json);
+        }
+    }
+
+    #[test]
+    fn test_invalid_paths() {
+        assert_invalid(r#"{"term": {"field": "field1"}}"#, "term");
+        assert_invalid(r#"{"term": {"field": "", "term": "aaa"}}"#, "term.field");
+        assert_invalid(
+            r#"{"term": {"field": "field1", "term": "aaa", "boost": 2}}"#,
+            "term.boost",
+        );
+        assert_invalid(r#"{"match": {"field": "field1"}}"#, "");
+        assert_invalid(r#"[]"#, "");
+        assert_invalid(
+            r#"{"phrase": {"field": "field1", "terms": ["aaa", 42]}}"#,
+            "phrase.terms[1]",
+        );
+        assert_invalid(
+            r#"{"phrase": {"field": "field1", "terms": ["aaa", ""]}}"#,
+            "phrase.terms[1]",
+        );
+        assert_invalid(
+            r#"{"phrase": {"field": "field1", "terms": ["aaa", "bbb"], "slop": 0}}"#,
+            "phrase.slop",
+        );
+        assert_invalid(
+            r#"{"bool": {"must": [
+                {"term": {"field": "field1", "term": "aaa"}},
+                {"phrase": {"field": "field1", "terms": ["aaa"]}}
+            ]}}"#,
+            "bool.must[1].phrase.terms",
+        );
+        assert_invalid(
+            r#"{"bool": {
+                "must": [{"term": {"field": "field1", "term": "aaa"}}],
+                "must_not": [{"bool": {"must": [{"term": {"field": "field1", "slop": 1}}]}}]
+            }}"#,
+            "bool.must_not[0].bool.must[0].term.slop",
+        );
+    }
+
+    #[test]
+    fn test_execute_parsed_query() {
+        let mut index: Index = Default::default();
+        index
+            .set_mapping(String::from("field1"), WhiteSpaceTokenizer::new())
+            .unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("field1", "aaa bbb");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("field1", "aaa ccc");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("field1", "bbb aaa ccc");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = IndexSearcher::new(&index);
+
+        let query = from_json(
+            r#"{"bool": {
+                "must": [{"term": {"field": "field1", "term": "aaa"}}],
+                "must_not": [{"term": {"field": "field1", "term": "bbb"}}]
+            }}"#,
+        ).unwrap();
+        let mut iter = query.execute(&index_search);
+
+        let next_doc = iter.next();
+        assert_eq!(next_doc, Some(SearchHit::new(1)));
+
+        let next_doc = iter.next();
+        assert_eq!(next_doc, None);
+
+        // no must clause matches no document
+        let query = from_json(
+            r#"{"bool": {"must_not": [{"term": {"field": "field1", "term": "bbb"}}]}}"#,
+        ).unwrap();
+        assert_eq!(query.execute(&index_search).next(), None);
+    }
+}
@@ -0,0 +1,9 @@
+//! Errors thrown while building queries.
+
+/// Possible query errors.
+#[derive(Debug, Fail)]
+pub enum QueryError {
+    /// The query description is not valid. The path points to the offending element.
+    #[fail(display = "invalid query at {}: {}", path, reason)]
+    InvalidQuery { path: String, reason: String },
+}
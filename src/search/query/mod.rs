@@ -5,14 +5,21 @@
 //! - a [`phrase query`][phrase]: match documents that have a specific sequence of terms.
 //! - a [`term query`][term]: match documents that have a specific term occurring.
 //!
+//! Queries can also be described in JSON thanks to the [`dsl`][dsl] module.
+//!
 //! [boolean]: boolean_query/index.html
 //! [phrase]: phrase_query/index.html
 //! [term]: term_query/index.html
+//! [dsl]: dsl/index.html
 use super::IndexSearcher;
 use super::SearchHit;
+use super::explanation::Explanation;
+use serde::{Serialize, Serializer};
 use std::fmt::Debug;
 
 pub mod boolean_query;
+pub mod dsl;
+pub mod error;
 pub mod phrase_query;
 pub mod term_query;
 
@@ -23,4 +30,38 @@ pub trait Query: Debug {
         &'q self,
         index_search: &'i IndexSearcher,
     ) -> Box<Iterator<Item = SearchHit> + 'q>;
+
+    /// Returns the [`dsl::QueryDsl`] representation of this query.
+    fn to_dsl(&self) -> dsl::QueryDsl;
+
+    /// Returns an [`Explanation`] of why the document with the given ID matches this query or
+    /// not.
+    fn explain(&self, index_search: &IndexSearcher, doc_id: u32) -> Explanation;
+}
+
+impl<'a> Query for Box<Query + 'a> {
+    fn execute<'q, 'i: 'q>(
+        &'q self,
+        index_search: &'i IndexSearcher,
+    ) -> Box<Iterator<Item = SearchHit> + 'q> {
+        (**self).execute(index_search)
+    }
+
+    fn to_dsl(&self) -> dsl::QueryDsl {
+        (**self).to_dsl()
+    }
+
+    fn explain(&self, index_search: &IndexSearcher, doc_id: u32) -> Explanation {
+        (**self).explain(index_search, doc_id)
+    }
+}
+
+/// A query tree is serialized with its [`dsl::QueryDsl`] representation.
+impl<'a> Serialize for Query + 'a {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_dsl().serialize(serializer)
+    }
 }
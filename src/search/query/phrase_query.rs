@@ -15,26 +15,45 @@
 //! let mut pq = PhraseQuery::new("field1", vec!["aaa", "bbb"]);
 //! pq.set_slop(2);
 //! ```
+//!
+//! If the field is mapped with a [`TokenFilter::Shingles`][shingles] filter, the query may look up
+//! the shingles of its terms instead, which are fewer and rarer than the terms, see
+//! [`PhraseQuery::set_shingles`].
+//!
+//! [shingles]: ../../../tokenizer/filter/enum.TokenFilter.html#variant.Shingles
 use super::Query;
+use super::dsl::QueryDsl;
 use index::posting_lists::DocIdAndPosItem;
+use index::posting_lists::DocItem;
+use index::reader::IndexReader;
 use search::IndexSearcher;
 use search::SearchHit;
+use search::explanation::Explanation;
+use std::borrow::Cow;
+use tokenizer::Tokenizer;
+use tokenizer::filter::TokenFilter;
 
 #[derive(Debug)]
 pub struct PhraseQuery<'a> {
-    field: &'a str,
-    terms: Vec<&'a str>,
+    field: Cow<'a, str>,
+    terms: Vec<Cow<'a, str>>,
     slop: u8,
+    shingles: bool,
 }
 
 impl<'a> PhraseQuery<'a> {
     /// Creates a new phrase query for specified sequence of terms. The order of terms is not
     /// relevant while matching, but is while scoring.
-    pub fn new(field: &'a str, terms: Vec<&'a str>) -> PhraseQuery<'a> {
+    pub fn new<F, T>(field: F, terms: Vec<T>) -> PhraseQuery<'a>
+    where
+        F: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
         PhraseQuery {
-            field,
-            terms,
+            field: field.into(),
+            terms: terms.into_iter().map(Into::into).collect(),
             slop: 1,
+            shingles: false,
         }
     }
 
@@ -42,6 +61,54 @@ impl<'a> PhraseQuery<'a> {
     pub fn set_slop(&mut self, slop: u8) {
         self.slop = slop;
     }
+
+    /// Defines whether the shingles of the terms are looked up instead of the terms, when the
+    /// field is mapped with a [`TokenFilter::Shingles`] filter.
+    ///
+    /// The shingles match the terms in the given order, and are only used without slop, i.e.,
+    /// with a slop of 1.
+    pub fn set_shingles(&mut self, shingles: bool) {
+        self.shingles = shingles;
+    }
+
+    /// Returns the terms to look up in the index, see [`lookup_terms`].
+    fn lookup_terms(&self, index: &IndexReader) -> Vec<Cow<'_, str>> {
+        lookup_terms(
+            index.get_tokenizer(&self.field),
+            &self.terms,
+            self.slop,
+            self.shingles,
+        )
+    }
+}
+
+/// Returns the terms to look up in a field mapped with the given tokenizer for a phrase of the
+/// given terms, i.e., the largest shingles of the terms if `shingles` is enabled and the
+/// tokenizer has a [`TokenFilter::Shingles`] filter, or the terms themselves.
+pub fn lookup_terms<'t, T: AsRef<str>>(
+    tokenizer: Option<&Tokenizer>,
+    terms: &'t [T],
+    slop: u8,
+    shingles: bool,
+) -> Vec<Cow<'t, str>> {
+    let filters = match tokenizer {
+        Some(tokenizer) if shingles && slop <= 1 => tokenizer.get_filters(),
+        _ => return terms.iter().map(|term| Cow::Borrowed(term.as_ref())).collect(),
+    };
+    let shingles = filters.iter().find_map(|filter| match *filter {
+        TokenFilter::Shingles(ref shingles) => Some(shingles),
+        _ => None,
+    });
+    match shingles {
+        Some(shingles) if shingles.min_size <= terms.len() => {
+            let size = shingles.max_size.min(terms.len());
+            terms
+                .windows(size)
+                .map(|terms| Cow::Owned(shingles.join(terms)))
+                .collect()
+        }
+        _ => terms.iter().map(|term| Cow::Borrowed(term.as_ref())).collect(),
+    }
 }
 
 impl<'pq> Query for PhraseQuery<'pq> {
@@ -49,82 +116,165 @@ impl<'pq> Query for PhraseQuery<'pq> {
         &'q self,
         index_search: &'i IndexSearcher,
     ) -> Box<Iterator<Item = SearchHit> + 'q> {
-        let postings = self.terms
+        let terms = self.lookup_terms(index_search.get_reader());
+        let postings = terms
             .iter()
             .map(|term| {
                 Box::new(
                     index_search
-                        .get_index()
+                        .get_reader()
                         .get_postings_list(&format!("{}:{}", self.field, term))
                         .iter_docs_pos(),
                 )
             })
             .collect();
-        let mut positions = Vec::with_capacity(self.terms.len());
+        let mut positions = Vec::with_capacity(terms.len());
         let on_match = move |(doc_id, terms): (u32, Vec<DocIdAndPosItem>)| {
-            let term1 = &terms[0];
-            let terms_rest = &terms[1..];
-            let fit = |positions: &Vec<u32>, posx: &u32| {
-                for pos in positions.iter() {
-                    if pos != posx && (*pos as i32 - *posx as i32).abs() as u8 <= self.slop {
-                        return true;
-                    }
-                }
-                false
-            };
-            let past_all_positions = |positions: &Vec<u32>, posx: &u32| {
-                for pos in positions.iter() {
-                    if posx <= pos {
-                        return false;
-                    }
+            let terms: Vec<&[u32]> = terms.iter().map(|term| term.positions).collect();
+            if match_positions(self.slop, &terms, &mut positions) {
+                Some(SearchHit::new(doc_id))
+            } else {
+                None
+            }
+        };
+
+        Box::new(index_search.conjunction(postings).filter_map(on_match))
+    }
+
+    fn to_dsl(&self) -> QueryDsl {
+        QueryDsl::Phrase {
+            field: self.field.to_string(),
+            terms: self.terms.iter().map(|term| term.to_string()).collect(),
+            slop: self.slop,
+            shingles: self.shingles,
+        }
+    }
+
+    fn explain(&self, index_search: &IndexSearcher, doc_id: u32) -> Explanation {
+        let description = format!(
+            "phrase {}:\"{}\" with slop {}",
+            self.field,
+            self.terms.join(" "),
+            self.slop
+        );
+        let index = index_search.get_reader();
+        let num_docs = index.num_docs();
+
+        let lookup_terms = self.lookup_terms(index);
+        let mut terms = Vec::with_capacity(lookup_terms.len());
+        let mut details = Vec::with_capacity(lookup_terms.len());
+        for term in lookup_terms.iter() {
+            let posting = index.get_postings_list(&format!("{}:{}", self.field, term));
+            let term_description = format!("term {}:{}", self.field, term);
+            match posting
+                .iter_docs_pos()
+                .find(|doc| doc.get_doc_id() == doc_id)
+            {
+                None => details.push(Explanation::no_match(term_description)),
+                Some(doc) => {
+                    let weight = Explanation::term_weight(posting.len(), num_docs);
+                    let mut detail = Explanation::matched(
+                        weight,
+                        format!(
+                            "weight of {}, doc_freq={} num_docs={}",
+                            term_description,
+                            posting.len(),
+                            num_docs
+                        ),
+                    );
+                    detail.positions = doc.positions.to_vec();
+                    details.push(detail);
+                    terms.push(doc.positions);
                 }
-                true
-            };
-
-            // Algorithm mostly taken from https://nlp.stanford.edu/IR-book/html/htmledition/positional-indexes-1.html
-            for pos1 in term1.positions.iter() {
-                positions.clear();
-                positions.push(*pos1);
-
-                // in case there is only one term, there is no need to have another go at the
-                // positions to see if any valid combination still exists
-                let mut checked_all = terms_rest.len() == 1;
-                // because the match of terms can be done in any order, we may need to iterate
-                // the terms several times
-                loop {
-                    let candidates_count = positions.len();
-                    for termx in terms_rest.iter() {
-                        for posx in termx.positions.iter() {
-                            if fit(&positions, posx) {
-                                positions.push(*posx);
-                                // TODO: should not break here so that all occurring phrases
-                                // are found.
-                                // matching phrases should be added to a list that could be
-                                // used for scoring.
-                                break;
-                            } else if past_all_positions(&positions, posx) {
-                                break;
-                            }
-                        }
-                        if positions.len() == terms.len() {
-                            // match
-                            // TODO: a single match of the term is enough until the fix to
-                            // gather all occurring phrases is done
-                            return Some(SearchHit::new(doc_id));
-                        }
-                    }
-                    if checked_all && candidates_count == positions.len() {
-                        // no more matches in any order
+            }
+        }
+
+        let mut positions = Vec::with_capacity(lookup_terms.len());
+        let mut explanation = if terms.len() == lookup_terms.len()
+            && match_positions(self.slop, &terms, &mut positions)
+        {
+            let value = details.iter().map(|detail| detail.value).sum();
+            let mut explanation =
+                Explanation::matched(value, format!("{}, sum of:", description));
+            positions.sort();
+            explanation.positions = positions;
+            explanation
+        } else {
+            Explanation::no_match(description)
+        };
+        explanation.details = details;
+        explanation
+    }
+}
+
+/// Returns `true` if the terms, given as the list of positions of each term, occur within the
+/// slop of each other. The positions of the terms forming the phrase are stored into `positions`.
+pub fn match_positions(slop: u8, terms: &[&[u32]], positions: &mut Vec<u32>) -> bool {
+    let term1 = &terms[0];
+    let terms_rest = &terms[1..];
+    if terms_rest.is_empty() {
+        // a single term, e.g., the shingle of a whole phrase, matches at any of its positions
+        positions.clear();
+        positions.extend(term1.first());
+        return !positions.is_empty();
+    }
+    let fit = |positions: &Vec<u32>, posx: &u32| {
+        for pos in positions.iter() {
+            if pos != posx && (*pos as i32 - *posx as i32).abs() as u8 <= slop {
+                return true;
+            }
+        }
+        false
+    };
+    let past_all_positions = |positions: &Vec<u32>, posx: &u32| {
+        for pos in positions.iter() {
+            if posx <= pos {
+                return false;
+            }
+        }
+        true
+    };
+
+    // Algorithm mostly taken from https://nlp.stanford.edu/IR-book/html/htmledition/positional-indexes-1.html
+    for pos1 in term1.iter() {
+        positions.clear();
+        positions.push(*pos1);
+
+        // in case there is only one term, there is no need to have another go at the
+        // positions to see if any valid combination still exists
+        let mut checked_all = terms_rest.len() == 1;
+        // because the match of terms can be done in any order, we may need to iterate
+        // the terms several times
+        loop {
+            let candidates_count = positions.len();
+            for termx in terms_rest.iter() {
+                for posx in termx.iter() {
+                    if fit(positions, posx) {
+                        positions.push(*posx);
+                        // TODO: should not break here so that all occurring phrases
+                        // are found.
+                        // matching phrases should be added to a list that could be
+                        // used for scoring.
+                        break;
+                    } else if past_all_positions(positions, posx) {
                         break;
                     }
-                    checked_all = true;
+                }
+                if positions.len() == terms.len() {
+                    // match
+                    // TODO: a single match of the term is enough until the fix to
+                    // gather all occurring phrases is done
+                    return true;
                 }
             }
-            None
-        };
-
-        Box::new(index_search.conjunction(postings).filter_map(on_match))
+            if checked_all && candidates_count == positions.len() {
+                // no more matches in any order
+                break;
+            }
+            checked_all = true;
+        }
     }
+    false
 }
 
 #[cfg(test)]
@@ -135,6 +285,8 @@ mod tests {
     use index::document::Document;
     use search::IndexSearcher;
     use search::SearchHit;
+    use tokenizer::Tokenizer;
+    use tokenizer::filter::shingles::Shingles;
     use tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
 
     #[test]
@@ -261,4 +413,91 @@ mod tests {
         let next_doc = iter.next();
         expect!(next_doc).to(be_none());
     }
+
+    #[test]
+    fn test_shingles() {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::Shingles(Shingles::new(2, 2)));
+        let mut index: Index = Default::default();
+        index.set_mapping(String::from("field1"), tokenizer).unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("field1", "aaa bbb ccc");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("field1", "bbb aaa ccc");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("field1", "ccc aaa bbb");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = &IndexSearcher::new(&index);
+
+        // the shingles match the terms in order
+        let mut pq = PhraseQuery::new("field1", vec!["aaa", "bbb"]);
+        assert_eq!(pq.execute(index_search).count(), 3);
+        pq.set_shingles(true);
+        let hits: Vec<SearchHit> = pq.execute(index_search).collect();
+        assert_eq!(hits, vec![SearchHit::new(0), SearchHit::new(2)]);
+        assert!(pq.explain(index_search, 0).matched);
+        assert!(!pq.explain(index_search, 1).matched);
+
+        let mut pq = PhraseQuery::new("field1", vec!["aaa", "bbb", "ccc"]);
+        pq.set_shingles(true);
+        let hits: Vec<SearchHit> = pq.execute(index_search).collect();
+        assert_eq!(hits, vec![SearchHit::new(0)]);
+
+        // the terms are looked up with a slop
+        pq.set_slop(2);
+        assert_eq!(pq.execute(index_search).count(), 3);
+    }
+
+    #[test]
+    fn test_explain() {
+        let mut index: Index = Default::default();
+        index
+            .set_mapping(String::from("field1"), WhiteSpaceTokenizer::new())
+            .unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("field1", "aaa ccc bbb aaa");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("field1", "aaa ccc ddd bbb");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("field1", "aaa ccc");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = &IndexSearcher::new(&index);
+
+        let mut pq = PhraseQuery::new("field1", vec!["aaa", "bbb"]);
+        pq.set_slop(2);
+
+        let explanation = pq.explain(index_search, 0);
+        assert!(explanation.matched);
+        assert_eq!(explanation.positions, vec![1, 3]);
+        assert_eq!(explanation.details[0].positions, vec![1, 4]);
+        assert_eq!(explanation.details[1].positions, vec![3]);
+        assert_eq!(
+            explanation.value,
+            Explanation::term_weight(3, 3) + Explanation::term_weight(2, 3)
+        );
+
+        // the terms are too far apart
+        let explanation = pq.explain(index_search, 1);
+        assert!(!explanation.matched);
+        assert!(explanation.details[0].matched);
+        assert!(explanation.details[1].matched);
+
+        // the second term is missing
+        let explanation = pq.explain(index_search, 2);
+        assert!(!explanation.matched);
+        assert!(explanation.details[0].matched);
+        assert!(!explanation.details[1].matched);
+    }
 }
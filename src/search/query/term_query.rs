@@ -9,20 +9,30 @@
 //! let tq = TermQuery::new("field1", "aaa");
 //! ```
 use super::Query;
+use super::dsl::QueryDsl;
 use index::posting_lists::DocItem;
 use search::IndexSearcher;
 use search::SearchHit;
+use search::explanation::Explanation;
+use std::borrow::Cow;
 
 #[derive(Debug)]
 pub struct TermQuery<'a> {
-    field: &'a str,
-    term: &'a str,
+    field: Cow<'a, str>,
+    term: Cow<'a, str>,
 }
 
 impl<'a> TermQuery<'a> {
     /// Creates a new term query for the specified term.
-    pub fn new(field: &'a str, term: &'a str) -> TermQuery<'a> {
-        TermQuery { field, term }
+    pub fn new<F, T>(field: F, term: T) -> TermQuery<'a>
+    where
+        F: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        TermQuery {
+            field: field.into(),
+            term: term.into(),
+        }
     }
 }
 
@@ -31,14 +41,55 @@ impl<'tq> Query for TermQuery<'tq> {
         &'q self,
         index_search: &'i IndexSearcher,
     ) -> Box<Iterator<Item = SearchHit> + 'q> {
+        let index = index_search.get_reader();
         Box::new(
-            index_search
-                .get_index()
-                .get_postings_list(&format!("{}:{}", self.field, self.term))
+            index
+                .get_postings_list(&format!(
+                    "{}:{}",
+                    self.field,
+                    index.normalize(&self.field, &self.term)
+                ))
                 .iter_docs()
                 .map(|doc| SearchHit::new(doc.get_doc_id())),
         )
     }
+
+    fn to_dsl(&self) -> QueryDsl {
+        QueryDsl::Term {
+            field: self.field.to_string(),
+            term: self.term.to_string(),
+        }
+    }
+
+    fn explain(&self, index_search: &IndexSearcher, doc_id: u32) -> Explanation {
+        let index = index_search.get_reader();
+        let term = index.normalize(&self.field, &self.term);
+        let description = format!("term {}:{}", self.field, term);
+        let posting = index.get_postings_list(&format!("{}:{}", self.field, term));
+        let doc = posting
+            .iter_docs_pos()
+            .find(|doc| doc.get_doc_id() == doc_id);
+        match doc {
+            None => Explanation::no_match(description),
+            Some(doc) => {
+                let num_docs = index.num_docs();
+                let tf = doc.positions.len() as f32;
+                let weight = Explanation::term_weight(posting.len(), num_docs);
+
+                let mut explanation =
+                    Explanation::matched(tf * weight, format!("{}, product of:", description));
+                explanation.positions = doc.positions.to_vec();
+                explanation
+                    .details
+                    .push(Explanation::matched(tf, String::from("term frequency")));
+                explanation.details.push(Explanation::matched(
+                    weight,
+                    format!("term weight, doc_freq={} num_docs={}", posting.len(), num_docs),
+                ));
+                explanation
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -49,6 +100,9 @@ mod tests {
     use index::document::Document;
     use search::IndexSearcher;
     use search::SearchHit;
+    use tokenizer::Tokenizer;
+    use tokenizer::filter::TokenFilter;
+    use tokenizer::keyword_tokenizer::KeywordTokenizer;
     use tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
 
     #[test]
@@ -84,4 +138,64 @@ mod tests {
         let next_doc = iter.next();
         expect!(next_doc).to(be_none());
     }
+
+    #[test]
+    fn test_normalized_term() {
+        let mut index: Index = Default::default();
+        let mut tokenizer = KeywordTokenizer::new();
+        tokenizer.add_filter(TokenFilter::LowerCase);
+        index.set_mapping(String::from("tag"), tokenizer).unwrap();
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::LowerCase);
+        index.set_mapping(String::from("field1"), tokenizer).unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("tag", "In Progress");
+        doc.add_field("field1", "In Progress");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = &IndexSearcher::new(&index);
+
+        let tq = TermQuery::new("tag", "IN PROGRESS");
+        let mut iter = tq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), None);
+        assert!(tq.explain(index_search, 0).matched);
+
+        // the white space tokenizer is not a normalizer
+        let tq = TermQuery::new("field1", "Progress");
+        let mut iter = tq.execute(index_search);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_explain() {
+        let mut index: Index = Default::default();
+        index
+            .set_mapping(String::from("field1"), WhiteSpaceTokenizer::new())
+            .unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("field1", "aaa bbb aaa");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("field1", "bbb");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = &IndexSearcher::new(&index);
+
+        let tq = TermQuery::new("field1", "aaa");
+
+        let explanation = tq.explain(index_search, 0);
+        assert!(explanation.matched);
+        assert_eq!(explanation.positions, vec![1, 3]);
+        assert_eq!(explanation.details[0].value, 2.0);
+        assert_eq!(explanation.details[1].value, Explanation::term_weight(1, 2));
+        assert_eq!(explanation.value, 2.0 * Explanation::term_weight(1, 2));
+
+        let explanation = tq.explain(index_search, 1);
+        assert!(!explanation.matched);
+        assert_eq!(explanation.value, 0.0);
+    }
 }
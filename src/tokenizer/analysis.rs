@@ -0,0 +1,162 @@
+//! Inspect the stages of the tokenization of an input, e.g., to find out why a query does not
+//! match.
+//!
+//! An [`Analysis`] holds the input as transformed by the char filters of a [`Tokenizer`], and the
+//! outputted tokens, each with the list of the token filters which produced or modified it. A
+//! filter is named by its index in the list of filters and its name in the JSON representation
+//! of the [analyzers][analyzer], e.g., `filters[2].stemmer`.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::tokenizer::Tokenizer;
+//! use ::iryfful::tokenizer::analyzer::Analyzer;
+//!
+//! let analyzer = Analyzer::from_json(r#"{
+//!     "tokenizer": "standard",
+//!     "filters": ["lowercase", {"stop_words": {"language": "english"}}, {"stemmer": "porter"}]
+//! }"#).unwrap();
+//!
+//! let analysis = analyzer.analyze("The Indexing of Rust");
+//! let filters: Vec<(&str, Vec<&str>)> = analysis
+//!     .tokens
+//!     .iter()
+//!     .map(|analyzed| {
+//!         let filters = analyzed.filters.iter().map(String::as_str).collect();
+//!         (analyzed.token.token.as_str(), filters)
+//!     })
+//!     .collect();
+//!
+//! assert_eq!(filters, vec![
+//!     ("index", vec!["filters[0].lowercase", "filters[2].stemmer"]),
+//!     ("rust", vec!["filters[0].lowercase"]),
+//! ]);
+//! ```
+//!
+//! [analyzer]: ../analyzer/index.html
+use super::filter::Filter;
+use super::{char_filter, correct_offsets, split_tokens, Token, Tokenizer};
+
+/// The stages of the tokenization of an input.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Analysis {
+    /// The input transformed by the char filters, which is split by the tokenizer
+    pub text: String,
+    /// The outputted tokens, whose offsets point into the original input
+    pub tokens: Vec<AnalyzedToken>,
+}
+
+/// A [`Token`] with the token filters it went through.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AnalyzedToken {
+    #[serde(flatten)]
+    pub token: Token,
+    /// The filters which produced or modified the token, in order, the token being a split
+    /// outputted as is by the tokenizer if the list is empty
+    pub filters: Vec<String>,
+}
+
+/// Returns the stages of the tokenization of the given input by the tokenizer.
+///
+/// A filter is deemed to have modified a token if it outputs a different token at the same
+/// position and offsets, otherwise an outputted token which was not in its input is deemed to
+/// be produced by the filter.
+pub fn analyze<T: Tokenizer + ?Sized>(tokenizer: &T, input: &str) -> Analysis {
+    let (text, corrections) = char_filter(tokenizer, input);
+    let mut tokens: Vec<AnalyzedToken> = split_tokens(tokenizer, &text)
+        .map(|token| AnalyzedToken {
+            token,
+            filters: Vec::new(),
+        })
+        .collect();
+    for (i, filter) in tokenizer.get_filters().iter().enumerate() {
+        let stage = format!("filters[{}].{}", i, filter.name());
+        let input: Vec<Token> = tokens.iter().map(|analyzed| analyzed.token.clone()).collect();
+        let output: Vec<Token> = filter.apply(Box::new(input.into_iter())).collect();
+        tokens = output
+            .into_iter()
+            .map(|token| {
+                let filters = match tokens.iter().find(|analyzed| analyzed.token == token) {
+                    Some(unchanged) => unchanged.filters.clone(),
+                    None => {
+                        let mut filters = tokens
+                            .iter()
+                            .find(|analyzed| {
+                                analyzed.token.position == token.position
+                                    && analyzed.token.start_offset == token.start_offset
+                                    && analyzed.token.end_offset == token.end_offset
+                            })
+                            .map(|modified| modified.filters.clone())
+                            .unwrap_or_default();
+                        filters.push(stage.clone());
+                        filters
+                    }
+                };
+                AnalyzedToken { token, filters }
+            })
+            .collect();
+    }
+    for analyzed in &mut tokens {
+        analyzed.token = correct_offsets(analyzed.token.clone(), &corrections);
+    }
+    Analysis { text, tokens }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+    use tokenizer::analyzer::Analyzer;
+
+    fn filters(analysis: &Analysis) -> Vec<(&str, Vec<&str>)> {
+        analysis
+            .tokens
+            .iter()
+            .map(|analyzed| {
+                (
+                    analyzed.token.token.as_str(),
+                    analyzed.filters.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_produced_tokens() {
+        let analyzer = Analyzer::from_json(
+            r#"{
+                "char_filters": ["html_strip"],
+                "tokenizer": "whitespace",
+                "filters": [
+                    {"synonyms": {"rules": ["tv => television"]}},
+                    {"ascii_folding": {"preserve_original": true}},
+                    {"shingles": {}}
+                ]
+            }"#,
+        ).unwrap();
+
+        let analysis = analyzer.analyze("<b>tv</b> café");
+        assert_eq!(analysis.text, "tv café");
+        assert_eq!(
+            filters(&analysis),
+            vec![
+                ("television", vec!["filters[0].synonyms"]),
+                ("television café", vec!["filters[2].shingles"]),
+                ("café", vec![]),
+                ("cafe", vec!["filters[1].ascii_folding"]),
+            ]
+        );
+        assert_eq!(analysis.tokens[2].token.start_offset, 10);
+    }
+
+    #[test]
+    fn test_json() {
+        let analyzer = Analyzer::from_json(r#"{"tokenizer": "keyword", "filters": ["lowercase"]}"#)
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&analyzer.analyze("Rust")).unwrap(),
+            r#"{"text":"Rust","tokens":[{"position":1,"token":"rust","position_length":1,"start_offset":0,"end_offset":4,"keyword":false,"filters":["filters[0].lowercase"]}]}"#
+        );
+    }
+}
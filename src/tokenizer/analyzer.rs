@@ -0,0 +1,557 @@
+//! An [`Analyzer`] composes char filters, a tokenizer and token filters into a single
+//! [`Tokenizer`], which can be registered under a name on an [`Index`][index] and described in
+//! JSON.
+//!
+//! The JSON representation of an analyzer is an object with the following keys, where the
+//! `char_filters` and `filters` lists are optional:
+//! - `char_filters`: a list of [`CharFilter`]s, i.e., `"html_strip"`,
+//!   `{"mapping": {"mappings": {"ﬁ": "fi"}}}` or
+//!   `{"pattern_replace": {"pattern": "(\\d+)-(\\d+)", "replacement": "$1$2"}}`;
+//! - `tokenizer`: the tokenizer splitting the input, i.e., `"standard"`, `"whitespace"`,
+//!   `"keyword"`, `"cjk"`, `{"ngram": {"min_gram": 2, "max_gram": 3, "keep": ["letter"]}}`,
+//!   `{"edge_ngram": {...}}`, `{"path_hierarchy": {"delimiter": "/", "reverse": false}}` or `{"pattern":
+//!   {"pattern": "\\.", "group": 1}}`, where the pattern matches the separators unless a capture
+//!   group is given, `0` being the whole match;
+//! - `filters`: a list of [`TokenFilter`]s, i.e., `"lowercase"`, `{"stop_words": {"language":
+//!   "english"}}` or `{"stop_words": {"words": ["a", "the"]}}`, `{"keyword_marker": {"words":
+//!   [...]}}`, `{"stemmer": "porter"}`, `{"normalize": "nfkc"}`, `{"ascii_folding":
+//!   {"preserve_original": false}}`, `{"length": {"min": 2, "max": 50}}`, `{"truncate": {"length":
+//!   20}}`, `{"phonetic": {"encoder": "double_metaphone", "preserve_original": true}}`,
+//!   `{"synonyms": {"rules": ["tv, television"]}}` or `{"synonyms": {"path": "synonyms.txt"}}`,
+//!   `{"shingles": {"min_size": 2, "max_size": 3}}` or `{"word_delimiter": {"catenate_words":
+//!   true}}`.
+//!
+//! The path of the offending element is reported in the [`TokenizerError`] returned when the JSON
+//! document is not a valid analyzer, e.g., `filters[1].stop_words`. The path of the root of the
+//! document is empty, e.g., for a missing tokenizer.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::tokenizer::Tokenizer;
+//! use ::iryfful::tokenizer::analyzer::Analyzer;
+//!
+//! let analyzer = Analyzer::from_json(r#"{
+//!     "char_filters": ["html_strip"],
+//!     "tokenizer": "standard",
+//!     "filters": ["lowercase", {"stop_words": {"language": "english"}}, {"stemmer": "porter"}]
+//! }"#).unwrap();
+//!
+//! let tokens: Vec<String> = analyzer
+//!     .tokenize("<p>The <b>Indexing</b> of documents</p>")
+//!     .map(|token| token.token)
+//!     .collect();
+//!
+//! assert_eq!(tokens, vec!["index", "document"]);
+//! ```
+//!
+//! [index]: ../../index/struct.Index.html
+use super::Tokenizer;
+use super::char_filter::CharFilter;
+use super::cjk_tokenizer::CjkTokenizer;
+use super::error::TokenizerError;
+use super::filter::phonetic::PhoneticEncoder;
+use super::filter::shingles::Shingles;
+use super::filter::stemmer::Stemmer;
+use super::filter::synonyms::SynonymMap;
+use super::filter::word_delimiter::WordDelimiter;
+use super::filter::{NormalizationForm, TokenFilter};
+use super::keyword_tokenizer::KeywordTokenizer;
+use super::language::Language;
+use super::ngram_tokenizer::{CharClass, EdgeNGramTokenizer, NGramTokenizer};
+use super::path_hierarchy_tokenizer::PathHierarchyTokenizer;
+use super::pattern_tokenizer::PatternTokenizer;
+use super::standard_tokenizer::StandardTokenizer;
+use super::whitespace_tokenizer::WhiteSpaceTokenizer;
+use serde_json;
+use serde_path_to_error;
+use std::collections::BTreeMap;
+
+type TokenizerResult<T> = Result<T, TokenizerError>;
+
+/// A [`Tokenizer`] made of char filters, the splits of another tokenizer and token filters.
+pub struct Analyzer {
+    char_filters: Vec<CharFilter>,
+    tokenizer: Box<Tokenizer + Send + Sync>,
+    filters: Vec<TokenFilter>,
+}
+
+impl Tokenizer for Analyzer {
+    fn add_filter(&mut self, filter: TokenFilter) {
+        self.filters.push(filter);
+    }
+
+    fn get_filters(&self) -> &Vec<TokenFilter> {
+        &self.filters
+    }
+
+    fn add_char_filter(&mut self, char_filter: CharFilter) {
+        self.char_filters.push(char_filter);
+    }
+
+    fn get_char_filters(&self) -> &Vec<CharFilter> {
+        &self.char_filters
+    }
+
+    fn splits<'a>(&self, input: &'a str) -> Box<Iterator<Item = &'a str> + 'a> {
+        self.tokenizer.splits(input)
+    }
+
+    fn splits_with_increments<'a>(
+        &self,
+        input: &'a str,
+    ) -> Box<Iterator<Item = (u32, &'a str)> + 'a> {
+        self.tokenizer.splits_with_increments(input)
+    }
+
+    fn is_normalizer(&self) -> bool {
+        self.tokenizer.is_normalizer()
+    }
+}
+
+impl Analyzer {
+    /// Creates an analyzer splitting the input with the given tokenizer.
+    ///
+    /// Only the splits of the tokenizer are used, the char filters and token filters being those
+    /// added to the analyzer.
+    pub fn new<T: Tokenizer + Send + Sync + 'static>(tokenizer: T) -> Analyzer {
+        Analyzer {
+            char_filters: Vec::new(),
+            tokenizer: Box::new(tokenizer),
+            filters: Vec::new(),
+        }
+    }
+
+    /// Creates the analyzer described by the given JSON document.
+    ///
+    /// # Errors
+    ///
+    /// See [`parse_json`] and [`AnalyzerDsl::into_analyzer`].
+    pub fn from_json(json: &str) -> TokenizerResult<Analyzer> {
+        parse_json(json)?.into_analyzer("")
+    }
+}
+
+/// The serializable representation of an [`Analyzer`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AnalyzerDsl {
+    #[serde(default)]
+    pub char_filters: Vec<CharFilterDsl>,
+    pub tokenizer: TokenizerDsl,
+    #[serde(default)]
+    pub filters: Vec<TokenFilterDsl>,
+}
+
+/// The serializable representation of a [`CharFilter`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum CharFilterDsl {
+    /// A [`CharFilter::HtmlStrip`] filter.
+    HtmlStrip,
+    /// A [`CharFilter::Mapping`] filter.
+    Mapping { mappings: BTreeMap<String, String> },
+    /// A [`CharFilter::PatternReplace`] filter.
+    PatternReplace {
+        pattern: String,
+        #[serde(default)]
+        replacement: String,
+    },
+}
+
+/// The serializable representation of a built-in [`Tokenizer`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum TokenizerDsl {
+    /// A [`StandardTokenizer`].
+    Standard,
+    /// A [`WhiteSpaceTokenizer`].
+    Whitespace,
+    /// A [`KeywordTokenizer`].
+    Keyword,
+    /// A [`CjkTokenizer`].
+    Cjk,
+    /// An [`NGramTokenizer`].
+    Ngram {
+        min_gram: usize,
+        max_gram: usize,
+        #[serde(default)]
+        keep: Vec<CharClass>,
+    },
+    /// An [`EdgeNGramTokenizer`].
+    EdgeNgram {
+        min_gram: usize,
+        max_gram: usize,
+        #[serde(default)]
+        keep: Vec<CharClass>,
+    },
+    /// A [`PathHierarchyTokenizer`].
+    PathHierarchy {
+        delimiter: char,
+        #[serde(default)]
+        reverse: bool,
+    },
+    /// A [`PatternTokenizer`].
+    Pattern {
+        pattern: String,
+        #[serde(default)]
+        group: Option<usize>,
+    },
+}
+
+/// The serializable representation of a [`TokenFilter`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum TokenFilterDsl {
+    /// A [`TokenFilter::LowerCase`] filter.
+    #[serde(rename = "lowercase")]
+    LowerCase,
+    /// A [`TokenFilter::StopWords`] filter, with the given words or those of the language.
+    StopWords {
+        #[serde(default)]
+        language: Option<Language>,
+        #[serde(default)]
+        words: Vec<String>,
+    },
+    /// A [`TokenFilter::KeywordMarker`] filter.
+    KeywordMarker { words: Vec<String> },
+    /// A [`TokenFilter::Stemmer`] filter.
+    Stemmer(Stemmer),
+    /// A [`TokenFilter::Normalize`] filter.
+    Normalize(NormalizationForm),
+    /// A [`TokenFilter::AsciiFolding`] filter.
+    AsciiFolding {
+        #[serde(default)]
+        preserve_original: bool,
+    },
+    /// A [`TokenFilter::Length`] filter, without bounds by default.
+    Length {
+        #[serde(default)]
+        min: usize,
+        #[serde(default = "default_max_length")]
+        max: usize,
+    },
+    /// A [`TokenFilter::Truncate`] filter.
+    Truncate { length: usize },
+    /// A [`TokenFilter::Phonetic`] filter.
+    Phonetic {
+        encoder: PhoneticEncoder,
+        #[serde(default)]
+        preserve_original: bool,
+    },
+    /// A [`TokenFilter::Synonyms`] filter, with the given rules or those of the file.
+    Synonyms {
+        #[serde(default)]
+        rules: Vec<String>,
+        #[serde(default)]
+        path: Option<String>,
+    },
+    /// A [`TokenFilter::Shingles`] filter.
+    Shingles(Shingles),
+    /// A [`TokenFilter::WordDelimiter`] filter.
+    WordDelimiter(WordDelimiter),
+}
+
+impl AnalyzerDsl {
+    /// Creates the described analyzer.
+    ///
+    /// # Errors
+    ///
+    /// An [`TokenizerError::InvalidAnalyzer`] error is returned with the path of the first invalid
+    /// element, prefixed with the given path, e.g., if a pattern is not a valid regular
+    /// expression.
+    pub fn into_analyzer(self, path: &str) -> TokenizerResult<Analyzer> {
+        let mut analyzer = Analyzer {
+            char_filters: Vec::new(),
+            tokenizer: self.tokenizer.into_tokenizer(&join(path, "tokenizer"))?,
+            filters: Vec::new(),
+        };
+        for (i, char_filter) in self.char_filters.into_iter().enumerate() {
+            let path = format!("{}[{}]", join(path, "char_filters"), i);
+            analyzer.add_char_filter(char_filter.into_char_filter(&path)?);
+        }
+        for (i, filter) in self.filters.into_iter().enumerate() {
+            let path = format!("{}[{}]", join(path, "filters"), i);
+            analyzer.add_filter(filter.into_filter(&path)?);
+        }
+        Ok(analyzer)
+    }
+}
+
+impl CharFilterDsl {
+    fn into_char_filter(self, path: &str) -> TokenizerResult<CharFilter> {
+        match self {
+            CharFilterDsl::HtmlStrip => Ok(CharFilter::HtmlStrip),
+            CharFilterDsl::Mapping { mappings } => Ok(CharFilter::Mapping(
+                mappings
+                    .into_iter()
+                    .filter(|(from, _)| !from.is_empty())
+                    .collect(),
+            )),
+            CharFilterDsl::PatternReplace {
+                pattern,
+                replacement,
+            } => CharFilter::pattern_replace(&pattern, &replacement)
+                .map_err(|err| invalid(join(path, "pattern_replace.pattern"), err)),
+        }
+    }
+}
+
+impl TokenizerDsl {
+    fn into_tokenizer(self, path: &str) -> TokenizerResult<Box<Tokenizer + Send + Sync>> {
+        let check_grams = |name: &str, min_gram: usize, max_gram: usize| {
+            if min_gram == 0 || min_gram > max_gram {
+                Err(invalid(
+                    join(path, name),
+                    "the n-gram lengths must be such that 1 <= min_gram <= max_gram",
+                ))
+            } else {
+                Ok(())
+            }
+        };
+        let tokenizer: Box<Tokenizer + Send + Sync> = match self {
+            TokenizerDsl::Standard => Box::new(StandardTokenizer::new()),
+            TokenizerDsl::Whitespace => Box::new(WhiteSpaceTokenizer::new()),
+            TokenizerDsl::Keyword => Box::new(KeywordTokenizer::new()),
+            TokenizerDsl::Cjk => Box::new(CjkTokenizer::new()),
+            TokenizerDsl::Ngram {
+                min_gram,
+                max_gram,
+                keep,
+            } => {
+                check_grams("ngram", min_gram, max_gram)?;
+                let mut tokenizer = NGramTokenizer::new(min_gram, max_gram);
+                for class in keep {
+                    tokenizer.keep(class);
+                }
+                Box::new(tokenizer)
+            }
+            TokenizerDsl::EdgeNgram {
+                min_gram,
+                max_gram,
+                keep,
+            } => {
+                check_grams("edge_ngram", min_gram, max_gram)?;
+                let mut tokenizer = EdgeNGramTokenizer::new(min_gram, max_gram);
+                for class in keep {
+                    tokenizer.keep(class);
+                }
+                Box::new(tokenizer)
+            }
+            TokenizerDsl::PathHierarchy { delimiter, reverse } => if reverse {
+                Box::new(PathHierarchyTokenizer::reverse(delimiter))
+            } else {
+                Box::new(PathHierarchyTokenizer::new(delimiter))
+            },
+            TokenizerDsl::Pattern { pattern, group } => {
+                let tokenizer = match group {
+                    None => PatternTokenizer::split(&pattern),
+                    Some(0) => PatternTokenizer::matches(&pattern),
+                    Some(group) => PatternTokenizer::group(&pattern, group),
+                };
+                Box::new(tokenizer.map_err(|err| invalid(join(path, "pattern"), err))?)
+            }
+        };
+        Ok(tokenizer)
+    }
+}
+
+fn default_max_length() -> usize {
+    usize::MAX
+}
+
+impl TokenFilterDsl {
+    fn into_filter(self, path: &str) -> TokenizerResult<TokenFilter> {
+        let filter = match self {
+            TokenFilterDsl::LowerCase => TokenFilter::LowerCase,
+            TokenFilterDsl::StopWords { language, words } => match (language, words.is_empty()) {
+                (Some(language), true) => TokenFilter::stop_words(language),
+                (None, false) => TokenFilter::StopWords(words.into_iter().collect()),
+                _ => {
+                    return Err(invalid(
+                        join(path, "stop_words"),
+                        "either a language or a list of words is required",
+                    ))
+                }
+            },
+            TokenFilterDsl::KeywordMarker { words } => {
+                TokenFilter::KeywordMarker(words.into_iter().collect())
+            }
+            TokenFilterDsl::Stemmer(stemmer) => TokenFilter::Stemmer(stemmer),
+            TokenFilterDsl::Normalize(form) => TokenFilter::Normalize(form),
+            TokenFilterDsl::AsciiFolding { preserve_original } => {
+                TokenFilter::AsciiFolding { preserve_original }
+            }
+            TokenFilterDsl::Length { min, max } => {
+                if min > max {
+                    return Err(invalid(
+                        join(path, "length"),
+                        "the lengths must be such that min <= max",
+                    ));
+                }
+                TokenFilter::Length { min, max }
+            }
+            TokenFilterDsl::Truncate { length } => {
+                if length == 0 {
+                    return Err(invalid(
+                        join(path, "truncate"),
+                        "the length must be at least 1",
+                    ));
+                }
+                TokenFilter::Truncate { length }
+            }
+            TokenFilterDsl::Phonetic {
+                encoder,
+                preserve_original,
+            } => TokenFilter::Phonetic {
+                encoder,
+                preserve_original,
+            },
+            TokenFilterDsl::Synonyms { rules, path: file } => {
+                let path = join(path, "synonyms");
+                let synonyms = match (file, rules.is_empty()) {
+                    (Some(file), true) => SynonymMap::load(file),
+                    (None, false) => SynonymMap::parse(&rules.join("\n")),
+                    _ => {
+                        return Err(invalid(
+                            path,
+                            "either a list of rules or the path of a file is required",
+                        ))
+                    }
+                };
+                TokenFilter::Synonyms(synonyms.map_err(|err| invalid(path, err))?)
+            }
+            TokenFilterDsl::Shingles(shingles) => {
+                if shingles.min_size < 2 || shingles.min_size > shingles.max_size {
+                    return Err(invalid(
+                        join(path, "shingles"),
+                        "the shingle sizes must be such that 2 <= min_size <= max_size",
+                    ));
+                }
+                TokenFilter::Shingles(shingles)
+            }
+            TokenFilterDsl::WordDelimiter(delimiter) => TokenFilter::WordDelimiter(delimiter),
+        };
+        Ok(filter)
+    }
+}
+
+/// Parses the JSON representation of an analyzer.
+///
+/// # Errors
+///
+/// An [`TokenizerError::InvalidAnalyzer`] error is returned if the JSON document is malformed or
+/// does not describe an analyzer.
+pub fn parse_json(json: &str) -> TokenizerResult<AnalyzerDsl> {
+    let deserializer = &mut serde_json::Deserializer::from_str(json);
+    serde_path_to_error::deserialize(deserializer)
+        .map_err(|err| invalid(path_of(err.path()), err.inner()))
+}
+
+/// Returns the given path as reported in errors, i.e., empty for the root rather than `.`.
+fn path_of(path: &serde_path_to_error::Path) -> String {
+    if path.iter().next().is_none() {
+        String::new()
+    } else {
+        path.to_string()
+    }
+}
+
+fn join(path: &str, element: &str) -> String {
+    if path.is_empty() {
+        element.to_string()
+    } else {
+        format!("{}.{}", path, element)
+    }
+}
+
+fn invalid<E: ToString>(path: String, reason: E) -> TokenizerError {
+    TokenizerError::InvalidAnalyzer {
+        path,
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_invalid(json: &str, expected_path: &str) {
+        match Analyzer::from_json(json) {
+            Err(TokenizerError::InvalidAnalyzer { path, .. }) => assert_eq!(path, expected_path),
+            Err(err) => panic!("expected an error at {}, got {}", expected_path, err),
+            Ok(_) => panic!("expected an error at {}", expected_path),
+        }
+    }
+
+    fn tokens(analyzer: &Analyzer, input: &str) -> Vec<(u32, String)> {
+        analyzer
+            .tokenize(input)
+            .map(|token| (token.position, token.token))
+            .collect()
+    }
+
+    #[test]
+    fn test_from_json() {
+        let analyzer = Analyzer::from_json(
+            r#"{
+                "char_filters": [{"mapping": {"mappings": {"&": " and "}}}],
+                "tokenizer": {"pattern": {"pattern": "[a-z]+|\\d+", "group": 0}},
+                "filters": [
+                    {"synonyms": {"rules": ["rock and roll => rock'n'roll"]}},
+                    {"shingles": {"max_size": 3, "output_unigrams": false}}
+                ]
+            }"#,
+        ).unwrap();
+
+        assert_eq!(
+            tokens(&analyzer, "rock&roll 1955"),
+            vec![(1, String::from("rock'n'roll 1955"))]
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let json = r#"{"char_filters":["html_strip"],"tokenizer":{"path_hierarchy":{"delimiter":"/","reverse":true}},"filters":["lowercase",{"stemmer":"french"},{"ascii_folding":{"preserve_original":true}}]}"#;
+        let dsl = parse_json(json).unwrap();
+        assert_eq!(serde_json::to_string(&dsl).unwrap(), json);
+    }
+
+    #[test]
+    fn test_invalid_analyzers() {
+        assert_invalid(r#"{"filters": []}"#, "");
+        assert_invalid(r#"{"tokenizer": "unknown"}"#, "tokenizer");
+        assert_invalid(
+            r#"{"tokenizer": {"ngram": {"min_gram": 3, "max_gram": 2}}}"#,
+            "tokenizer.ngram",
+        );
+        assert_invalid(
+            r#"{"tokenizer": {"pattern": {"pattern": "("}}}"#,
+            "tokenizer.pattern",
+        );
+        assert_invalid(
+            r#"{"tokenizer": "standard", "char_filters": [{"pattern_replace": {"pattern": "["}}]}"#,
+            "char_filters[0].pattern_replace.pattern",
+        );
+        assert_invalid(
+            r#"{"tokenizer": "standard", "filters": ["lowercase", {"stop_words": {}}]}"#,
+            "filters[1].stop_words",
+        );
+        assert_invalid(
+            r#"{"tokenizer": "standard", "filters": [{"synonyms": {"rules": ["a => b => c"]}}]}"#,
+            "filters[0].synonyms",
+        );
+        assert_invalid(
+            r#"{"tokenizer": "standard", "filters": [{"stemmer": "latin"}]}"#,
+            "filters[0].stemmer",
+        );
+        assert_invalid(
+            r#"{"tokenizer": "standard", "filters": [{"length": {"min": 3, "max": 2}}]}"#,
+            "filters[0].length",
+        );
+        assert_invalid(
+            r#"{"tokenizer": "standard", "filters": ["lowercase", {"truncate": {"length": 0}}]}"#,
+            "filters[1].truncate",
+        );
+    }
+}
@@ -0,0 +1,346 @@
+//! Transform the input string before it is split by a [`Tokenizer`][tokenizer].
+//!
+//! Available char filters:
+//! - [`CharFilter::HtmlStrip`]: removes the HTML markup and decodes the character references
+//! - [`CharFilter::Mapping`]: replaces strings with others, e.g., ligatures with their letters
+//! - [`CharFilter::PatternReplace`]: replaces the matches of a regular expression
+//!
+//! A char filter keeps track of the parts of the input it replaced, so that the offsets of the
+//! tokens point into the original input rather than the filtered one.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::tokenizer::Tokenizer;
+//! use ::iryfful::tokenizer::char_filter::CharFilter;
+//! use ::iryfful::tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+//!
+//! let mut tokenizer = WhiteSpaceTokenizer::new();
+//! tokenizer.add_char_filter(CharFilter::HtmlStrip);
+//!
+//! let input = "<p>fish &amp; <b>chips</b></p>";
+//! let tokens: Vec<(String, &str)> = tokenizer
+//!     .tokenize(input)
+//!     .map(|token| {
+//!         let source = &input[token.start_offset as usize..token.end_offset as usize];
+//!         (token.token, source)
+//!     })
+//!     .collect();
+//!
+//! assert_eq!(tokens, vec![
+//!     (String::from("fish"), "fish"),
+//!     (String::from("&"), "&amp;"),
+//!     (String::from("chips"), "chips"),
+//! ]);
+//! ```
+//!
+//! [tokenizer]: ../trait.Tokenizer.html
+use super::error::TokenizerError;
+use regex::Regex;
+use std::char;
+
+/// Type of possible builtin char filters.
+pub enum CharFilter {
+    /// Removes the HTML tags and comments, along with the content of the `script` and `style`
+    /// elements, and decodes the character references, e.g., `&amp;` into `&`
+    ///
+    /// The tags of block elements, such as `p` or `br`, are replaced with a line feed, so that the
+    /// words they separate are not joined.
+    HtmlStrip,
+    /// Replaces the strings with their replacement, the longest string being replaced first
+    Mapping(Vec<(String, String)>),
+    /// Replaces the matches of the regular expression with the replacement, which may refer to
+    /// the capture groups, e.g., `$1`
+    PatternReplace(Regex, String),
+}
+
+/// The part of the input replaced by a char filter, given as byte offsets.
+#[derive(Clone, Debug, PartialEq)]
+struct Replacement {
+    input_start: u32,
+    input_end: u32,
+    output_start: u32,
+    output_end: u32,
+}
+
+/// The corrections to apply to offsets in the output of a char filter, so that they point into
+/// its input.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OffsetCorrection {
+    replacements: Vec<Replacement>,
+}
+
+impl OffsetCorrection {
+    /// Returns the offset in the input of the character at the given offset in the output.
+    ///
+    /// A character of a replacement is mapped to the start of the replaced part.
+    pub fn correct_start(&self, offset: u32) -> u32 {
+        let i = self.replacements.partition_point(|r| r.output_end <= offset);
+        match self.replacements.get(i) {
+            Some(r) if r.output_start <= offset => return r.input_start,
+            _ => {}
+        }
+        match i.checked_sub(1).map(|i| &self.replacements[i]) {
+            Some(r) => r.input_end + (offset - r.output_end),
+            None => offset,
+        }
+    }
+
+    /// Returns the offset in the input following the character preceding the given offset in
+    /// the output.
+    ///
+    /// A character of a replacement is mapped to the end of the replaced part.
+    pub fn correct_end(&self, offset: u32) -> u32 {
+        let i = self.replacements.partition_point(|r| r.output_start < offset);
+        match i.checked_sub(1).map(|i| &self.replacements[i]) {
+            Some(r) if offset <= r.output_end => r.input_end,
+            Some(r) => r.input_end + (offset - r.output_end),
+            None => offset,
+        }
+    }
+}
+
+impl CharFilter {
+    /// Creates a [`CharFilter::Mapping`] filter from the given pairs of strings and replacements.
+    pub fn mapping(pairs: &[(&str, &str)]) -> CharFilter {
+        CharFilter::Mapping(
+            pairs
+                .iter()
+                .filter(|&&(from, _)| !from.is_empty())
+                .map(|&(from, to)| (from.to_string(), to.to_string()))
+                .collect(),
+        )
+    }
+
+    /// Creates a [`CharFilter::PatternReplace`] filter.
+    ///
+    /// # Errors
+    ///
+    /// A [`TokenizerError::InvalidPattern`] error is returned if the pattern is not a valid
+    /// regular expression.
+    pub fn pattern_replace(pattern: &str, replacement: &str) -> Result<CharFilter, TokenizerError> {
+        let regex = Regex::new(pattern).map_err(|err| TokenizerError::InvalidPattern {
+            pattern: pattern.to_string(),
+            reason: err.to_string(),
+        })?;
+        Ok(CharFilter::PatternReplace(regex, replacement.to_string()))
+    }
+
+    /// Returns the filtered text, along with the corrections of its offsets.
+    pub fn filter(&self, text: &str) -> (String, OffsetCorrection) {
+        let replacements = match *self {
+            CharFilter::HtmlStrip => html_strip(text),
+            CharFilter::Mapping(ref pairs) => mapping(pairs, text),
+            CharFilter::PatternReplace(ref regex, ref replacement) => regex
+                .captures_iter(text)
+                .filter_map(|captures| {
+                    let m = captures.get(0)?;
+                    let mut replaced = String::new();
+                    captures.expand(replacement, &mut replaced);
+                    Some((m.start(), m.end(), replaced))
+                })
+                .collect(),
+        };
+        rewrite(text, replacements)
+    }
+}
+
+/// Returns the text with the given parts, as sorted ranges of byte offsets, replaced.
+fn rewrite(text: &str, replacements: Vec<(usize, usize, String)>) -> (String, OffsetCorrection) {
+    let mut output = String::with_capacity(text.len());
+    let mut correction: OffsetCorrection = Default::default();
+    let mut last = 0;
+    for (start, end, replaced) in replacements {
+        output.push_str(&text[last..start]);
+        let output_start = output.len() as u32;
+        output.push_str(&replaced);
+        correction.replacements.push(Replacement {
+            input_start: start as u32,
+            input_end: end as u32,
+            output_start,
+            output_end: output.len() as u32,
+        });
+        last = end;
+    }
+    output.push_str(&text[last..]);
+    (output, correction)
+}
+
+fn mapping(pairs: &[(String, String)], text: &str) -> Vec<(usize, usize, String)> {
+    let mut replacements = Vec::new();
+    let mut i = 0;
+    while i < text.len() {
+        let longest = pairs
+            .iter()
+            .filter(|(from, _)| text[i..].starts_with(from.as_str()))
+            .max_by_key(|(from, _)| from.len());
+        match longest {
+            Some((from, to)) => {
+                replacements.push((i, i + from.len(), to.clone()));
+                i += from.len();
+            }
+            None => i += text[i..].chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    replacements
+}
+
+/// The elements whose tags separate words.
+const BLOCK_ELEMENTS: &[&str] = &[
+    "address", "article", "aside", "blockquote", "br", "dd", "div", "dl", "dt", "footer", "h1",
+    "h2", "h3", "h4", "h5", "h6", "header", "hr", "li", "main", "nav", "ol", "p", "pre", "section",
+    "table", "td", "th", "tr", "ul",
+];
+
+/// Returns the character of the given reference, without its leading `&` and trailing `;`.
+fn decode_reference(reference: &str) -> Option<char> {
+    if let Some(hex) = reference.strip_prefix("#x").or_else(|| reference.strip_prefix("#X")) {
+        return u32::from_str_radix(hex, 16).ok().and_then(char::from_u32);
+    }
+    if let Some(decimal) = reference.strip_prefix('#') {
+        return decimal.parse().ok().and_then(char::from_u32);
+    }
+    let c = match reference {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        "copy" => '©',
+        "reg" => '®',
+        "hellip" => '…',
+        "mdash" => '—',
+        "ndash" => '–',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        _ => return None,
+    };
+    Some(c)
+}
+
+/// Returns the end of the tag starting at the given offset, along with the name of the element
+/// and whether it is a closing tag.
+fn parse_tag(text: &str, start: usize) -> Option<(usize, String, bool)> {
+    let rest = &text[start + 1..];
+    let closing = rest.starts_with('/');
+    let name: String = rest[closing as usize..]
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect();
+    // a comparison such as `a < b` is not a tag
+    if name.is_empty() && !rest.starts_with('!') && !rest.starts_with('?') {
+        return None;
+    }
+    let end = start + 1 + rest.find('>')? + 1;
+    Some((end, name.to_ascii_lowercase(), closing))
+}
+
+fn html_strip(text: &str) -> Vec<(usize, usize, String)> {
+    let mut replacements = Vec::new();
+    let mut i = 0;
+    while let Some(found) = text[i..].find(['<', '&']) {
+        let start = i + found;
+        i = start + 1;
+        if text[start..].starts_with("<!--") {
+            let end = text[start..].find("-->").map_or(text.len(), |end| start + end + 3);
+            replacements.push((start, end, String::new()));
+            i = end;
+        } else if text[start..].starts_with('<') {
+            let (mut end, name, closing) = match parse_tag(text, start) {
+                Some(tag) => tag,
+                None => continue,
+            };
+            if !closing && (name == "script" || name == "style") {
+                // the content of the element is removed along with its tags
+                let close = format!("</{}", name);
+                end = text[end..]
+                    .to_ascii_lowercase()
+                    .find(&close)
+                    .and_then(|close| text[end + close..].find('>').map(|i| end + close + i + 1))
+                    .unwrap_or(text.len());
+            }
+            let replaced = if BLOCK_ELEMENTS.contains(&name.as_str()) {
+                String::from("\n")
+            } else {
+                String::new()
+            };
+            replacements.push((start, end, replaced));
+            i = end;
+        } else {
+            let reference = text[i..]
+                .find(';')
+                .filter(|&len| len <= 10)
+                .and_then(|len| decode_reference(&text[i..i + len]).map(|c| (c, len)));
+            if let Some((c, len)) = reference {
+                let end = i + len + 1;
+                replacements.push((start, end, c.to_string()));
+                i = end;
+            }
+        }
+    }
+    replacements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokenizer::Tokenizer;
+    use tokenizer::standard_tokenizer::StandardTokenizer;
+
+    fn tokens<T: Tokenizer>(tokenizer: &T, input: &str) -> Vec<(String, String)> {
+        tokenizer
+            .tokenize(input)
+            .map(|token| {
+                let source = &input[token.start_offset as usize..token.end_offset as usize];
+                (token.token, source.to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_html_strip() {
+        let (text, _) = CharFilter::HtmlStrip.filter(
+            "<!DOCTYPE html><p>a&lt;b<br/>c <!-- d -->e</p>\
+             <script>f</SCRIPT><style type=\"text/css\">g</style>h &#233;&#x20AC; 1 < 2 &unknown;",
+        );
+        assert_eq!(text, "\na<b\nc e\nh é€ 1 < 2 &unknown;");
+    }
+
+    #[test]
+    fn test_offsets() {
+        let mut tokenizer = StandardTokenizer::new();
+        tokenizer.add_char_filter(CharFilter::HtmlStrip);
+        tokenizer.add_char_filter(CharFilter::mapping(&[("ﬁ", "fi"), ("+", " plus ")]));
+
+        assert_eq!(
+            tokens(&tokenizer, "<i>ﬁsh</i>+<b>AT&amp;T</b> caf&#233;"),
+            vec![
+                (String::from("fish"), String::from("ﬁsh")),
+                (String::from("plus"), String::from("+")),
+                (String::from("AT"), String::from("AT")),
+                (String::from("T"), String::from("T")),
+                (String::from("café"), String::from("caf&#233;")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pattern_replace() {
+        let mut tokenizer = StandardTokenizer::new();
+        tokenizer.add_char_filter(CharFilter::pattern_replace(r"(\d+)-(\d+)", "$1$2").unwrap());
+
+        assert_eq!(
+            tokens(&tokenizer, "call 555-1234 now"),
+            vec![
+                (String::from("call"), String::from("call")),
+                (String::from("5551234"), String::from("555-1234")),
+                (String::from("now"), String::from("now")),
+            ]
+        );
+
+        assert!(CharFilter::pattern_replace("(", "").is_err());
+    }
+}
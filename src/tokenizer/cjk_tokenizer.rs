@@ -0,0 +1,243 @@
+//! A [`Tokenizer`] that splits Chinese, Japanese and Korean text into overlapping bigrams.
+//!
+//! CJK scripts do not separate words with spaces, so a run of Han, Hiragana, Katakana or Hangul
+//! characters is split into all its pairs of consecutive characters, at consecutive positions:
+//! a [`PhraseQuery`][phrase] over the bigrams of a query finds the documents containing it. A run
+//! of a single character gives a unigram.
+//!
+//! Other text is split on word boundaries, as by the [`StandardTokenizer`][standard].
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::tokenizer::Tokenizer;
+//! use ::iryfful::tokenizer::cjk_tokenizer::CjkTokenizer;
+//!
+//! let tokenizer = CjkTokenizer::new();
+//! let splits: Vec<&str> = tokenizer.splits("東京都 SD500 カード").collect();
+//!
+//! assert_eq!(splits, vec!["東京", "京都", "SD500", "カー", "ード"]);
+//! ```
+//!
+//! [phrase]: ../../search/query/phrase_query/index.html
+//! [standard]: ../standard_tokenizer/struct.StandardTokenizer.html
+use super::Tokenizer;
+use super::char_filter::CharFilter;
+use super::filter::TokenFilter;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Returns whether the given character is a Han, Hiragana, Katakana or Hangul character.
+pub fn is_cjk(c: char) -> bool {
+    match c {
+        // Han ideographs, with the iteration mark
+        '\u{3005}' | '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' => true,
+        '\u{F900}'..='\u{FAFF}' | '\u{20000}'..='\u{2FA1F}' => true,
+        // Hiragana and Katakana, including the halfwidth forms
+        '\u{3040}'..='\u{30FF}' | '\u{31F0}'..='\u{31FF}' | '\u{FF66}'..='\u{FF9F}' => true,
+        // Hangul syllables and jamo
+        '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' | '\u{AC00}'..='\u{D7AF}' => true,
+        _ => false,
+    }
+}
+
+pub struct CjkTokenizer {
+    filters: Vec<TokenFilter>,
+    char_filters: Vec<CharFilter>,
+}
+
+impl Tokenizer for CjkTokenizer {
+    fn add_filter(&mut self, filter: TokenFilter) {
+        self.filters.push(filter);
+    }
+
+    fn get_filters(&self) -> &Vec<TokenFilter> {
+        &self.filters
+    }
+
+    fn add_char_filter(&mut self, char_filter: CharFilter) {
+        self.char_filters.push(char_filter);
+    }
+
+    fn get_char_filters(&self) -> &Vec<CharFilter> {
+        &self.char_filters
+    }
+
+    fn splits<'a>(&self, input: &'a str) -> Box<Iterator<Item = &'a str> + 'a> {
+        let mut splits = Splits {
+            input,
+            splits: Vec::new(),
+            run: Vec::new(),
+            run_end: 0,
+        };
+        for (start, segment) in input.split_word_bound_indices() {
+            if !segment.chars().any(char::is_alphanumeric) {
+                splits.flush_run();
+                continue;
+            }
+            let mut word_start = None;
+            for (offset, c) in segment.char_indices() {
+                let offset = start + offset;
+                if is_cjk(c) {
+                    if let Some(word_start) = word_start.take() {
+                        splits.push_word(word_start, offset);
+                    }
+                    splits.push_cjk(offset, offset + c.len_utf8());
+                } else if word_start.is_none() {
+                    word_start = Some(offset);
+                }
+            }
+            if let Some(word_start) = word_start {
+                splits.push_word(word_start, start + segment.len());
+            }
+        }
+        splits.flush_run();
+        Box::new(splits.splits.into_iter())
+    }
+}
+
+impl CjkTokenizer {
+    pub fn new() -> CjkTokenizer {
+        CjkTokenizer {
+            filters: Vec::new(),
+            char_filters: Vec::new(),
+        }
+    }
+}
+
+/// The splits of an input, while it is being split.
+struct Splits<'a> {
+    input: &'a str,
+    splits: Vec<&'a str>,
+    /// The byte offsets of the characters of the current CJK run
+    run: Vec<usize>,
+    run_end: usize,
+}
+
+impl<'a> Splits<'a> {
+    fn push_cjk(&mut self, start: usize, end: usize) {
+        if self.run_end != start {
+            self.flush_run();
+        }
+        self.run.push(start);
+        self.run_end = end;
+    }
+
+    fn push_word(&mut self, start: usize, end: usize) {
+        self.flush_run();
+        let word = &self.input[start..end];
+        if word.chars().any(char::is_alphanumeric) {
+            self.splits.push(word);
+        }
+    }
+
+    fn flush_run(&mut self) {
+        let input = self.input;
+        let run_end = self.run_end;
+        match self.run.len() {
+            0 => {}
+            1 => self.splits.push(&input[self.run[0]..run_end]),
+            n => for i in 0..(n - 1) {
+                let end = if i + 2 < n { self.run[i + 2] } else { run_end };
+                self.splits.push(&input[self.run[i]..end]);
+            },
+        }
+        self.run.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use index::Index;
+    use index::document::Document;
+    use search::IndexSearcher;
+    use search::SearchHit;
+    use search::query::Query;
+    use search::query::phrase_query::PhraseQuery;
+    use search::query::term_query::TermQuery;
+    use tokenizer::filter::TokenFilter;
+
+    fn splits(input: &str) -> Vec<&str> {
+        CjkTokenizer::new().splits(input).collect()
+    }
+
+    #[test]
+    fn test_bigrams() {
+        assert_eq!(splits("中华人民"), vec!["中华", "华人", "人民"]);
+        assert_eq!(splits("한국어 사전"), vec!["한국", "국어", "사전"]);
+        assert_eq!(
+            splits("ひらがなとカタカナ"),
+            vec!["ひら", "らが", "がな", "なと", "とカ", "カタ", "タカ", "カナ"]
+        );
+        assert_eq!(splits("東、京"), vec!["東", "京"]);
+    }
+
+    #[test]
+    fn test_mixed_scripts() {
+        assert_eq!(
+            splits("iPhone用ケース, 3.5mm 端子"),
+            vec!["iPhone", "用ケ", "ケー", "ース", "3.5mm", "端子"]
+        );
+        assert_eq!(splits("Rust 言語"), vec!["Rust", "言語"]);
+    }
+
+    #[test]
+    fn test_offsets() {
+        let tokenizer = CjkTokenizer::new();
+        let tokens: Vec<(u32, String, u32, u32)> = tokenizer
+            .tokenize("a 東京都")
+            .map(|token| {
+                (
+                    token.position,
+                    token.token,
+                    token.start_offset,
+                    token.end_offset,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                (1, String::from("a"), 0, 1),
+                (2, String::from("東京"), 2, 8),
+                (3, String::from("京都"), 5, 11),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_bigrams() {
+        let mut tokenizer = CjkTokenizer::new();
+        tokenizer.add_filter(TokenFilter::LowerCase);
+        let mut index: Index = Default::default();
+        index.set_mapping(String::from("name"), tokenizer).unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("name", "東京都の地図");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("name", "京都 Map");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = &IndexSearcher::new(&index);
+
+        let tq = TermQuery::new("name", "京都");
+        let mut iter = tq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), Some(SearchHit::new(1)));
+        assert_eq!(iter.next(), None);
+
+        // "東京都" is split into "東京" and "京都"
+        let pq = PhraseQuery::new("name", vec!["東京", "京都"]);
+        let mut iter = pq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), None);
+
+        let tq = TermQuery::new("name", "map");
+        let mut iter = tq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(1)));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,25 @@
+//! Errors thrown while configuring tokenizers.
+
+/// Possible tokenizer errors.
+#[derive(Debug, Fail)]
+pub enum TokenizerError {
+    /// The regular expression could not be compiled.
+    #[fail(display = "invalid pattern {}: {}", pattern, reason)]
+    InvalidPattern { pattern: String, reason: String },
+
+    /// The regular expression does not have the requested capture group.
+    #[fail(display = "missing capture group {} in pattern: {}", group, pattern)]
+    MissingCaptureGroup { pattern: String, group: usize },
+
+    /// A line of a synonym file is not a valid rule.
+    #[fail(display = "invalid synonym rule at line {}: {}", line, reason)]
+    InvalidSynonymRule { line: usize, reason: String },
+
+    /// The file could not be read.
+    #[fail(display = "unreadable file {}: {}", path, reason)]
+    UnreadableFile { path: String, reason: String },
+
+    /// The analyzer description is not valid. The path points to the offending element.
+    #[fail(display = "invalid analyzer at {}: {}", path, reason)]
+    InvalidAnalyzer { path: String, reason: String },
+}
@@ -0,0 +1,86 @@
+//! Folding of accented Latin characters into their ASCII equivalent, e.g., `café` into `cafe`.
+//!
+//! Characters with diacritics are replaced with their base letter, and letters without a
+//! decomposition, such as `ø` or `ß`, with their usual transliteration. Characters of other
+//! scripts are left untouched.
+use std::borrow::Cow;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::{decompose_canonical, is_combining_mark};
+
+/// Returns the ASCII transliteration of a Latin letter that has no canonical decomposition.
+fn transliterate(c: char) -> Option<&'static str> {
+    let ascii = match c {
+        'Æ' => "AE",
+        'æ' => "ae",
+        'Ð' | 'Đ' => "D",
+        'ð' | 'đ' => "d",
+        'Ħ' => "H",
+        'ħ' => "h",
+        'ı' => "i",
+        'Ĳ' => "IJ",
+        'ĳ' => "ij",
+        'Ŀ' | 'Ł' => "L",
+        'ŀ' | 'ł' => "l",
+        'Ŋ' => "N",
+        'ŋ' => "n",
+        'Ø' => "O",
+        'ø' => "o",
+        'Œ' => "OE",
+        'œ' => "oe",
+        'ß' => "ss",
+        'ſ' => "s",
+        'Þ' => "TH",
+        'þ' => "th",
+        'Ŧ' => "T",
+        'ŧ' => "t",
+        'ƒ' => "f",
+        _ => return None,
+    };
+    Some(ascii)
+}
+
+/// Returns `true` if the character belongs to the Latin blocks with accented letters.
+fn is_latin(c: char) -> bool {
+    ('\u{00C0}'..='\u{024F}').contains(&c) || ('\u{1E00}'..='\u{1EFF}').contains(&c)
+}
+
+/// Returns the given text with its accented Latin characters folded into ASCII.
+pub fn fold(text: &str) -> Cow<'_, str> {
+    if text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+    let mut folded = String::with_capacity(text.len());
+    // compose the text first, so that a letter followed by combining marks is folded as a whole
+    for c in text.nfc() {
+        if let Some(ascii) = transliterate(c) {
+            folded.push_str(ascii);
+        } else if is_latin(c) {
+            let mut decomposition = String::new();
+            decompose_canonical(c, |d| decomposition.push(d));
+            let mut chars = decomposition.chars();
+            match chars.next() {
+                Some(base) if base.is_ascii() && chars.all(is_combining_mark) => folded.push(base),
+                _ => folded.push(c),
+            }
+        } else if !(is_combining_mark(c) && folded.ends_with(|c: char| c.is_ascii())) {
+            // the marks left over on a folded letter are dropped
+            folded.push(c);
+        }
+    }
+    Cow::Owned(folded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold() {
+        assert_eq!(fold("café"), "cafe");
+        assert_eq!(fold("cafe\u{301}"), "cafe");
+        assert_eq!(fold("Ærøskøbing"), "AEroskobing");
+        assert_eq!(fold("Łódź straße Ṩ"), "Lodz strasse S");
+        assert_eq!(fold("ǅ ǈ"), "ǅ ǈ");
+        assert_eq!(fold("Москва 東京"), "Москва 東京");
+    }
+}
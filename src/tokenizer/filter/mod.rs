@@ -0,0 +1,478 @@
+//! Apply some operation over a stream of tokens.
+//!
+//! The filters of a [`Tokenizer`][tokenizer] are chained, each one reading the stream of tokens
+//! outputted by the previous one, so that a filter may see the tokens added by the previous filters
+//! and none of the removed ones.
+//!
+//! [tokenizer]: ../trait.Tokenizer.html
+pub mod ascii_folding;
+pub mod phonetic;
+pub mod shingles;
+pub mod stemmer;
+pub mod stop_words;
+pub mod synonyms;
+pub mod word_delimiter;
+
+use self::phonetic::PhoneticEncoder;
+use self::shingles::Shingles;
+use self::stemmer::Stemmer;
+use self::synonyms::SynonymMap;
+use self::word_delimiter::WordDelimiter;
+use super::Token;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use tokenizer::language::Language;
+use unicode_normalization::UnicodeNormalization;
+
+/// Filter interface allows to transform the stream of tokens outputted by a tokenizer
+///
+/// A filter may change the text of tokens, remove tokens or add new ones. The offsets of a token
+/// still point to the split it was created from, and its position is left untouched, so that
+/// removed tokens leave a gap in the positions.
+///
+/// A token added at the position of another one is stacked on it, e.g., a synonym, and a token
+/// replacing several ones spans their positions with its [`Token::position_length`]. The tokens
+/// must be returned by increasing position, as they are indexed in order.
+pub trait Filter {
+    fn apply<'a>(
+        &'a self,
+        tokens: Box<Iterator<Item = Token> + 'a>,
+    ) -> Box<Iterator<Item = Token> + 'a>;
+}
+
+/// A Unicode normalization form.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NormalizationForm {
+    /// Canonical composition, e.g., `e` followed by a combining acute accent becomes `é`
+    Nfc,
+    /// Compatibility composition, which also replaces compatibility characters, e.g., `ﬁ`
+    /// becomes `fi`
+    Nfkc,
+}
+
+/// Type of possible builtin [`Filter`]s.
+pub enum TokenFilter {
+    /// Returns a lowercased version of the token
+    LowerCase,
+    /// Removes the tokens found in the set of words
+    StopWords(HashSet<String>),
+    /// Marks the tokens found in the set of words as keywords, protecting them from stemming
+    KeywordMarker(HashSet<String>),
+    /// Replaces the token with its stem, unless it is a keyword
+    Stemmer(Stemmer),
+    /// Returns the token in the Unicode normalization form
+    Normalize(NormalizationForm),
+    /// Folds the accented Latin characters of the token into ASCII, see [`ascii_folding`]
+    ///
+    /// If `preserve_original` is `true`, a folded token is preceded by the original one at the
+    /// same position, so that the accented form can still be searched for exactly.
+    AsciiFolding { preserve_original: bool },
+    /// Replaces words and phrases with their synonyms, see [`synonyms`]
+    Synonyms(SynonymMap),
+    /// Adds the shingles of adjacent tokens, see [`shingles`]
+    Shingles(Shingles),
+    /// Splits the words into their parts, see [`word_delimiter`]
+    WordDelimiter(WordDelimiter),
+    /// Removes the tokens shorter than `min` or longer than `max` characters
+    Length { min: usize, max: usize },
+    /// Truncates the tokens to their first `length` characters
+    Truncate { length: usize },
+    /// Replaces the token with its phonetic codes, unless it is a keyword, see [`phonetic`]
+    ///
+    /// If `preserve_original` is `true`, the codes are preceded by the original token at the same
+    /// position. A token without code, e.g., a number, is left untouched.
+    Phonetic {
+        encoder: PhoneticEncoder,
+        preserve_original: bool,
+    },
+}
+
+impl TokenFilter {
+    /// Creates a [`TokenFilter::StopWords`] filter with the built-in list of stop words of the
+    /// given language.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ::iryfful::tokenizer::Tokenizer;
+    /// use ::iryfful::tokenizer::filter::TokenFilter;
+    /// use ::iryfful::tokenizer::language::Language;
+    /// use ::iryfful::tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+    ///
+    /// let mut tokenizer = WhiteSpaceTokenizer::new();
+    /// tokenizer.add_filter(TokenFilter::LowerCase);
+    /// tokenizer.add_filter(TokenFilter::stop_words(Language::English));
+    ///
+    /// let tokens: Vec<(u32, String)> = tokenizer
+    ///     .tokenize("The art of war")
+    ///     .map(|token| (token.position, token.token))
+    ///     .collect();
+    ///
+    /// assert_eq!(tokens, vec![(2, String::from("art")), (4, String::from("war"))]);
+    /// ```
+    pub fn stop_words(language: Language) -> TokenFilter {
+        TokenFilter::StopWords(
+            stop_words::of(language)
+                .iter()
+                .map(|word| word.to_string())
+                .collect(),
+        )
+    }
+
+    /// Returns the name of the filter in its JSON representation, e.g., `lowercase`.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            TokenFilter::LowerCase => "lowercase",
+            TokenFilter::StopWords(_) => "stop_words",
+            TokenFilter::KeywordMarker(_) => "keyword_marker",
+            TokenFilter::Stemmer(_) => "stemmer",
+            TokenFilter::Normalize(_) => "normalize",
+            TokenFilter::AsciiFolding { .. } => "ascii_folding",
+            TokenFilter::Synonyms(_) => "synonyms",
+            TokenFilter::Shingles(_) => "shingles",
+            TokenFilter::WordDelimiter(_) => "word_delimiter",
+            TokenFilter::Length { .. } => "length",
+            TokenFilter::Truncate { .. } => "truncate",
+            TokenFilter::Phonetic { .. } => "phonetic",
+        }
+    }
+}
+
+impl Filter for TokenFilter {
+    fn apply<'a>(
+        &'a self,
+        tokens: Box<Iterator<Item = Token> + 'a>,
+    ) -> Box<Iterator<Item = Token> + 'a> {
+        match *self {
+            TokenFilter::LowerCase => Box::new(tokens.map(|mut token| {
+                token.token = token.token.to_lowercase();
+                token
+            })),
+            TokenFilter::StopWords(ref words) => {
+                Box::new(tokens.filter(move |token| !words.contains(&token.token)))
+            }
+            TokenFilter::KeywordMarker(ref words) => Box::new(tokens.map(move |mut token| {
+                token.keyword = token.keyword || words.contains(&token.token);
+                token
+            })),
+            TokenFilter::Stemmer(stemmer) => Box::new(tokens.map(move |mut token| {
+                if !token.keyword {
+                    token.token = stemmer.stem(&token.token);
+                }
+                token
+            })),
+            TokenFilter::Normalize(form) => Box::new(tokens.map(move |mut token| {
+                token.token = match form {
+                    NormalizationForm::Nfc => token.token.nfc().collect(),
+                    NormalizationForm::Nfkc => token.token.nfkc().collect(),
+                };
+                token
+            })),
+            TokenFilter::AsciiFolding { preserve_original } => {
+                Box::new(tokens.flat_map(move |mut token| {
+                    let folded = match ascii_folding::fold(&token.token) {
+                        Cow::Borrowed(_) => None,
+                        Cow::Owned(folded) => Some(folded),
+                    };
+                    match folded {
+                        Some(ref folded) if *folded == token.token => vec![token],
+                        Some(folded) => if preserve_original {
+                            let original = token.clone();
+                            token.token = folded;
+                            vec![original, token]
+                        } else {
+                            token.token = folded;
+                            vec![token]
+                        },
+                        None => vec![token],
+                    }
+                }))
+            }
+            TokenFilter::Synonyms(ref synonyms) => synonyms.filter(tokens),
+            TokenFilter::Shingles(ref shingles) => shingles.filter(tokens),
+            TokenFilter::WordDelimiter(ref delimiter) => delimiter.filter(tokens),
+            TokenFilter::Length { min, max } => Box::new(tokens.filter(move |token| {
+                let length = token.token.chars().count();
+                min <= length && length <= max
+            })),
+            TokenFilter::Truncate { length } => Box::new(tokens.map(move |mut token| {
+                if let Some((end, _)) = token.token.char_indices().nth(length) {
+                    token.token.truncate(end);
+                }
+                token
+            })),
+            TokenFilter::Phonetic {
+                encoder,
+                preserve_original,
+            } => Box::new(tokens.flat_map(move |token| {
+                let codes = if token.keyword {
+                    Vec::new()
+                } else {
+                    encoder.encode(&token.token)
+                };
+                if codes.is_empty() {
+                    return vec![token];
+                }
+                let mut tokens = Vec::with_capacity(codes.len() + 1);
+                if preserve_original {
+                    tokens.push(token.clone());
+                }
+                for code in codes {
+                    if !preserve_original || code != token.token {
+                        tokens.push(Token {
+                            token: code,
+                            ..token.clone()
+                        });
+                    }
+                }
+                tokens
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use index::Index;
+    use index::document::Document;
+    use search::IndexSearcher;
+    use search::SearchHit;
+    use search::query::Query;
+    use search::query::phrase_query::PhraseQuery;
+    use search::query::term_query::TermQuery;
+    use tokenizer::Tokenizer;
+    use tokenizer::keyword_tokenizer::KeywordTokenizer;
+    use tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+
+    #[test]
+    fn test_custom_stop_words() {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::StopWords(
+            vec![String::from("foo"), String::from("bar")]
+                .into_iter()
+                .collect(),
+        ));
+
+        let tokens: Vec<(u32, String, u32)> = tokenizer
+            .tokenize("foo baz bar Bar")
+            .map(|token| (token.position, token.token, token.start_offset))
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![(2, String::from("baz"), 4), (4, String::from("Bar"), 12)]
+        );
+    }
+
+    #[test]
+    fn test_built_in_stop_words() {
+        for &language in Language::all() {
+            match TokenFilter::stop_words(language) {
+                TokenFilter::StopWords(words) => assert!(!words.is_empty()),
+                _ => panic!("expected a stop words filter"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_phrase_slop_over_stop_words() {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::LowerCase);
+        tokenizer.add_filter(TokenFilter::stop_words(Language::English));
+        let mut index: Index = Default::default();
+        index.set_mapping(String::from("title"), tokenizer).unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("title", "The Art of War");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = &IndexSearcher::new(&index);
+
+        // "art" and "war" are still two positions apart
+        let pq = PhraseQuery::new("title", vec!["art", "war"]);
+        let mut iter = pq.execute(index_search);
+        assert_eq!(iter.next(), None);
+
+        let mut pq = PhraseQuery::new("title", vec!["art", "war"]);
+        pq.set_slop(2);
+        let mut iter = pq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_stemmer() {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::LowerCase);
+        tokenizer.add_filter(TokenFilter::Stemmer(Stemmer::Porter));
+        let mut index: Index = Default::default();
+        index.set_mapping(String::from("body"), tokenizer).unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("body", "Documents are indexed");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = &IndexSearcher::new(&index);
+
+        // the query term is expected to be analyzed
+        let tq = TermQuery::new("body", "index");
+        let mut iter = tq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_keyword_marker() {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::KeywordMarker(
+            vec![String::from("news")].into_iter().collect(),
+        ));
+        tokenizer.add_filter(TokenFilter::Stemmer(Stemmer::Porter));
+
+        let tokens: Vec<(String, bool)> = tokenizer
+            .tokenize("news papers")
+            .map(|token| (token.token, token.keyword))
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![(String::from("news"), true), (String::from("paper"), false)]
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::Normalize(NormalizationForm::Nfc));
+        assert_eq!(tokenizer.normalize("cafe\u{301}"), "café");
+        assert_eq!(tokenizer.normalize("ﬁle"), "ﬁle");
+
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::Normalize(NormalizationForm::Nfkc));
+        assert_eq!(tokenizer.normalize("cafe\u{301}"), "café");
+        assert_eq!(tokenizer.normalize("ﬁle"), "file");
+    }
+
+    #[test]
+    fn test_ascii_folding() {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::AsciiFolding {
+            preserve_original: true,
+        });
+        let mut index: Index = Default::default();
+        index.set_mapping(String::from("menu"), tokenizer).unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("menu", "café crème");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("menu", "cafe\u{301} latte");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("menu", "cafe latte");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = &IndexSearcher::new(&index);
+
+        let tq = TermQuery::new("menu", "cafe");
+        let mut iter = tq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), Some(SearchHit::new(1)));
+        assert_eq!(iter.next(), Some(SearchHit::new(2)));
+        assert_eq!(iter.next(), None);
+
+        let tq = TermQuery::new("menu", "café");
+        let mut iter = tq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), None);
+
+        // the folded and original tokens are at the same position
+        let pq = PhraseQuery::new("menu", vec!["cafe", "creme"]);
+        let mut iter = pq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_phonetic() {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::Phonetic {
+            encoder: PhoneticEncoder::DoubleMetaphone,
+            preserve_original: true,
+        });
+
+        let tokens: Vec<(u32, String)> = tokenizer
+            .tokenize("Jo Smith 42")
+            .map(|token| (token.position, token.token))
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                (1, String::from("Jo")),
+                (1, String::from("J")),
+                (1, String::from("A")),
+                (2, String::from("Smith")),
+                (2, String::from("SM0")),
+                (2, String::from("XMT")),
+                (3, String::from("42")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_sound_alike_names() {
+        let mut tokenizer = KeywordTokenizer::new();
+        tokenizer.add_filter(TokenFilter::Phonetic {
+            encoder: PhoneticEncoder::DoubleMetaphone,
+            preserve_original: false,
+        });
+        let mut index: Index = Default::default();
+        index.set_mapping(String::from("surname"), tokenizer).unwrap();
+
+        let mut doc: Document = Default::default();
+        for surname in &["Smith", "Smyth", "Schmidt", "Jones"] {
+            doc.clear();
+            doc.add_field("surname", surname);
+            index.add_doc(&doc).unwrap();
+        }
+
+        let index_search = &IndexSearcher::new(&index);
+
+        // the query term is normalized into its primary code
+        let tq = TermQuery::new("surname", "Smithe");
+        let mut iter = tq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), Some(SearchHit::new(1)));
+        assert_eq!(iter.next(), None);
+
+        // the alternate code of "Smith" is the primary code of "Schmidt"
+        let tq = TermQuery::new("surname", "Schmitt");
+        let mut iter = tq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), Some(SearchHit::new(1)));
+        assert_eq!(iter.next(), Some(SearchHit::new(2)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_length_and_truncate() {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::Length { min: 2, max: 12 });
+        tokenizer.add_filter(TokenFilter::Truncate { length: 5 });
+
+        let tokens: Vec<(u32, String)> = tokenizer
+            .tokenize("a déjà internationalization référendum QUJDREVGR0hJSktMTU5PUA==")
+            .map(|token| (token.position, token.token))
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![(2, String::from("déjà")), (4, String::from("référ"))]
+        );
+    }
+}
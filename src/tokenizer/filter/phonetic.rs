@@ -0,0 +1,795 @@
+//! Phonetic encoding of words, so that words which sound alike, e.g., `Smith` and `Smyth`, get
+//! the same code.
+//!
+//! The encoders work on the Latin letters of the word, ignoring case, and are designed for names
+//! pronounced in English:
+//! - [Soundex][soundex] keeps the first letter followed by three digits, e.g., `S530`;
+//! - Refined Soundex keeps the first letter followed by a digit per group of letters, without
+//!   length limit, which tells apart more names, e.g., `S38060`;
+//! - Double Metaphone gives a primary code of up to four letters, e.g., `SM0`, and an alternate
+//!   one for words of foreign origin, e.g., `XMT`, following the implementation of Apache
+//!   Commons Codec.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::tokenizer::filter::phonetic::PhoneticEncoder;
+//!
+//! assert_eq!(PhoneticEncoder::Soundex.encode("Smyth"), vec!["S530"]);
+//! assert_eq!(PhoneticEncoder::DoubleMetaphone.encode("Smith"), vec!["SM0", "XMT"]);
+//! ```
+//!
+//! [soundex]: https://en.wikipedia.org/wiki/Soundex
+
+/// A phonetic encoding algorithm.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PhoneticEncoder {
+    /// The American Soundex algorithm
+    Soundex,
+    /// The Refined Soundex algorithm
+    RefinedSoundex,
+    /// The Double Metaphone algorithm
+    DoubleMetaphone,
+}
+
+impl PhoneticEncoder {
+    /// Returns the codes of the given word, i.e., a single code except for the distinct alternate
+    /// code of the Double Metaphone algorithm.
+    ///
+    /// No code is returned if the word has no letter the algorithm knows of.
+    pub fn encode(self, word: &str) -> Vec<String> {
+        let code = match self {
+            PhoneticEncoder::Soundex => soundex(word),
+            PhoneticEncoder::RefinedSoundex => refined_soundex(word),
+            PhoneticEncoder::DoubleMetaphone => return double_metaphone(word),
+        };
+        code.into_iter().collect()
+    }
+}
+
+/// Returns the uppercased ASCII letters of the word.
+fn letters(word: &str) -> Vec<char> {
+    word.chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// The Soundex digits of the letters `A` to `Z`, where `0` is a letter which is not coded.
+const SOUNDEX: &[u8; 26] = b"01230120022455012623010202";
+
+fn soundex(word: &str) -> Option<String> {
+    let letters = letters(word);
+    let first = *letters.first()?;
+    let mut code = first.to_string();
+    let mut last = SOUNDEX[(first as u8 - b'A') as usize];
+    for &c in &letters[1..] {
+        let digit = SOUNDEX[(c as u8 - b'A') as usize];
+        // `H` and `W` do not separate letters with the same digit
+        if c == 'H' || c == 'W' {
+            continue;
+        }
+        if digit != b'0' && digit != last {
+            code.push(digit as char);
+            if code.len() == 4 {
+                break;
+            }
+        }
+        last = digit;
+    }
+    while code.len() < 4 {
+        code.push('0');
+    }
+    Some(code)
+}
+
+/// The Refined Soundex digits of the letters `A` to `Z`.
+const REFINED_SOUNDEX: &[u8; 26] = b"01360240043788015936020505";
+
+fn refined_soundex(word: &str) -> Option<String> {
+    let letters = letters(word);
+    let mut code = letters.first()?.to_string();
+    let mut last = None;
+    for &c in &letters {
+        let digit = REFINED_SOUNDEX[(c as u8 - b'A') as usize];
+        if last != Some(digit) {
+            code.push(digit as char);
+            last = Some(digit);
+        }
+    }
+    Some(code)
+}
+
+/// The maximum length of the Double Metaphone codes.
+const METAPHONE_LENGTH: usize = 4;
+
+/// The primary and alternate Double Metaphone codes, while they are built.
+struct MetaphoneCodes {
+    primary: String,
+    alternate: String,
+}
+
+impl MetaphoneCodes {
+    fn append(&mut self, primary: &str, alternate: &str) {
+        self.append_primary(primary);
+        self.append_alternate(alternate);
+    }
+
+    fn append_both(&mut self, code: &str) {
+        self.append(code, code);
+    }
+
+    fn append_primary(&mut self, code: &str) {
+        append_truncated(&mut self.primary, code);
+    }
+
+    fn append_alternate(&mut self, code: &str) {
+        append_truncated(&mut self.alternate, code);
+    }
+
+    fn is_complete(&self) -> bool {
+        self.primary.len() >= METAPHONE_LENGTH && self.alternate.len() >= METAPHONE_LENGTH
+    }
+}
+
+fn append_truncated(code: &mut String, addition: &str) {
+    let remaining = METAPHONE_LENGTH.saturating_sub(code.len());
+    code.extend(addition.chars().take(remaining));
+}
+
+/// The uppercased word being encoded with Double Metaphone.
+struct MetaphoneWord {
+    chars: Vec<char>,
+    slavo_germanic: bool,
+}
+
+impl MetaphoneWord {
+    fn new(word: &str) -> MetaphoneWord {
+        let chars: Vec<char> = word.trim().chars().flat_map(char::to_uppercase).collect();
+        let mut word = MetaphoneWord {
+            chars,
+            slavo_germanic: false,
+        };
+        word.slavo_germanic = word.chars.contains(&'W') || word.chars.contains(&'K')
+            || word.find("CZ") || word.find("WITZ");
+        word
+    }
+
+    fn len(&self) -> isize {
+        self.chars.len() as isize
+    }
+
+    /// Returns the character at the given index, `'\0'` if it is out of bounds.
+    fn at(&self, index: isize) -> char {
+        if index < 0 || index >= self.len() {
+            '\0'
+        } else {
+            self.chars[index as usize]
+        }
+    }
+
+    fn is_vowel_at(&self, index: isize) -> bool {
+        "AEIOUY".contains(self.at(index))
+    }
+
+    /// Returns whether the characters at the given index are one of the candidates, which all
+    /// have the same length.
+    fn matches(&self, index: isize, candidates: &[&str]) -> bool {
+        let length = candidates[0].len() as isize;
+        if index < 0 || index + length > self.len() {
+            return false;
+        }
+        let slice = &self.chars[index as usize..(index + length) as usize];
+        candidates
+            .iter()
+            .any(|candidate| candidate.chars().eq(slice.iter().cloned()))
+    }
+
+    fn find(&self, pattern: &str) -> bool {
+        (0..self.len()).any(|index| self.matches(index, &[pattern]))
+    }
+
+    /// Returns the index following the letter at the given index, skipping the next letter if it
+    /// is one of the given ones.
+    fn skip(&self, index: isize, letters: &str) -> isize {
+        if letters.contains(self.at(index + 1)) {
+            index + 2
+        } else {
+            index + 1
+        }
+    }
+
+    fn starts_with_van_von_or_sch(&self) -> bool {
+        self.matches(0, &["VAN ", "VON "]) || self.matches(0, &["SCH"])
+    }
+}
+
+fn double_metaphone(word: &str) -> Vec<String> {
+    let word = MetaphoneWord::new(word);
+    if word.len() == 0 {
+        return Vec::new();
+    }
+    let mut codes = MetaphoneCodes {
+        primary: String::new(),
+        alternate: String::new(),
+    };
+    let mut index = if word.matches(0, &["GN", "KN", "PN", "WR", "PS"]) {
+        1
+    } else {
+        0
+    };
+    while !codes.is_complete() && index < word.len() {
+        index = match word.at(index) {
+            'A' | 'E' | 'I' | 'O' | 'U' | 'Y' => {
+                if index == 0 {
+                    codes.append_both("A");
+                }
+                index + 1
+            }
+            'B' => {
+                codes.append_both("P");
+                word.skip(index, "B")
+            }
+            'Ç' => {
+                codes.append_both("S");
+                index + 1
+            }
+            'C' => metaphone_c(&word, &mut codes, index),
+            'D' => metaphone_d(&word, &mut codes, index),
+            'F' => {
+                codes.append_both("F");
+                word.skip(index, "F")
+            }
+            'G' => metaphone_g(&word, &mut codes, index),
+            'H' => {
+                if (index == 0 || word.is_vowel_at(index - 1)) && word.is_vowel_at(index + 1) {
+                    codes.append_both("H");
+                    index + 2
+                } else {
+                    index + 1
+                }
+            }
+            'J' => metaphone_j(&word, &mut codes, index),
+            'K' => {
+                codes.append_both("K");
+                word.skip(index, "K")
+            }
+            'L' => {
+                if word.at(index + 1) == 'L' {
+                    if metaphone_spanish_ll(&word, index) {
+                        codes.append_primary("L");
+                    } else {
+                        codes.append_both("L");
+                    }
+                    index + 2
+                } else {
+                    codes.append_both("L");
+                    index + 1
+                }
+            }
+            'M' => {
+                codes.append_both("M");
+                let umb = word.matches(index - 1, &["UMB"])
+                    && (index + 1 == word.len() - 1 || word.matches(index + 2, &["ER"]));
+                if word.at(index + 1) == 'M' || umb {
+                    index + 2
+                } else {
+                    index + 1
+                }
+            }
+            'N' => {
+                codes.append_both("N");
+                word.skip(index, "N")
+            }
+            'Ñ' => {
+                codes.append_both("N");
+                index + 1
+            }
+            'P' => {
+                if word.at(index + 1) == 'H' {
+                    codes.append_both("F");
+                    index + 2
+                } else {
+                    codes.append_both("P");
+                    word.skip(index, "PB")
+                }
+            }
+            'Q' => {
+                codes.append_both("K");
+                word.skip(index, "Q")
+            }
+            'R' => {
+                // the final R of French words, e.g., `Rogier`
+                if index == word.len() - 1 && !word.slavo_germanic
+                    && word.matches(index - 2, &["IE"])
+                    && !word.matches(index - 4, &["ME", "MA"])
+                {
+                    codes.append_alternate("R");
+                } else {
+                    codes.append_both("R");
+                }
+                word.skip(index, "R")
+            }
+            'S' => metaphone_s(&word, &mut codes, index),
+            'T' => metaphone_t(&word, &mut codes, index),
+            'V' => {
+                codes.append_both("F");
+                word.skip(index, "V")
+            }
+            'W' => metaphone_w(&word, &mut codes, index),
+            'X' => {
+                if index == 0 {
+                    codes.append_both("S");
+                    index + 1
+                } else {
+                    // the final X of French words, e.g., `Breaux`
+                    let french = index == word.len() - 1
+                        && (word.matches(index - 3, &["IAU", "EAU"])
+                            || word.matches(index - 2, &["AU", "OU"]));
+                    if !french {
+                        codes.append_both("KS");
+                    }
+                    word.skip(index, "CX")
+                }
+            }
+            'Z' => {
+                if word.at(index + 1) == 'H' {
+                    codes.append_both("J");
+                    index + 2
+                } else {
+                    let slavo_germanic =
+                        word.slavo_germanic && index > 0 && word.at(index - 1) != 'T';
+                    if word.matches(index + 1, &["ZO", "ZI", "ZA"]) || slavo_germanic {
+                        codes.append("S", "TS");
+                    } else {
+                        codes.append_both("S");
+                    }
+                    word.skip(index, "Z")
+                }
+            }
+            _ => index + 1,
+        };
+    }
+    let MetaphoneCodes { primary, alternate } = codes;
+    if primary.is_empty() {
+        Vec::new()
+    } else if primary == alternate || alternate.is_empty() {
+        vec![primary]
+    } else {
+        vec![primary, alternate]
+    }
+}
+
+fn metaphone_c(word: &MetaphoneWord, codes: &mut MetaphoneCodes, index: isize) -> isize {
+    if metaphone_germanic_ch(word, index) {
+        codes.append_both("K");
+        index + 2
+    } else if index == 0 && word.matches(index, &["CAESAR"]) {
+        codes.append_both("S");
+        index + 2
+    } else if word.matches(index, &["CH"]) {
+        metaphone_ch(word, codes, index)
+    } else if word.matches(index, &["CZ"]) && !word.matches(index - 2, &["WICZ"]) {
+        // e.g., `Czerny`
+        codes.append("S", "X");
+        index + 2
+    } else if word.matches(index + 1, &["CIA"]) {
+        // e.g., `Focaccia`
+        codes.append_both("X");
+        index + 3
+    } else if word.matches(index, &["CC"]) && !(index == 1 && word.at(0) == 'M') {
+        // double C, but not `McClellan`
+        if word.matches(index + 2, &["I", "E", "H"]) && !word.matches(index + 2, &["HU"]) {
+            // e.g., `Bellocchio`, but `Accident` or `Succeed`
+            if (index == 1 && word.at(index - 1) == 'A')
+                || word.matches(index - 1, &["UCCEE", "UCCES"])
+            {
+                codes.append_both("KS");
+            } else {
+                codes.append_both("X");
+            }
+            index + 3
+        } else {
+            codes.append_both("K");
+            index + 2
+        }
+    } else if word.matches(index, &["CK", "CG", "CQ"]) {
+        codes.append_both("K");
+        index + 2
+    } else if word.matches(index, &["CI", "CE", "CY"]) {
+        // Italian or English
+        if word.matches(index, &["CIO", "CIE", "CIA"]) {
+            codes.append("S", "X");
+        } else {
+            codes.append_both("S");
+        }
+        index + 2
+    } else {
+        codes.append_both("K");
+        if word.matches(index + 1, &[" C", " Q", " G"]) {
+            // e.g., `Mac Caffrey`
+            index + 3
+        } else if word.matches(index + 1, &["C", "K", "Q"])
+            && !word.matches(index + 1, &["CE", "CI"])
+        {
+            index + 2
+        } else {
+            index + 1
+        }
+    }
+}
+
+/// Returns whether the C at the given index is a Germanic hard C, e.g., `Bacher`.
+fn metaphone_germanic_ch(word: &MetaphoneWord, index: isize) -> bool {
+    if word.matches(index, &["CHIA"]) {
+        true
+    } else if index <= 1 || word.is_vowel_at(index - 2) || !word.matches(index - 1, &["ACH"]) {
+        false
+    } else {
+        let c = word.at(index + 2);
+        (c != 'I' && c != 'E') || word.matches(index - 2, &["BACHER", "MACHER"])
+    }
+}
+
+fn metaphone_ch(word: &MetaphoneWord, codes: &mut MetaphoneCodes, index: isize) -> isize {
+    if index > 0 && word.matches(index, &["CHAE"]) {
+        // e.g., `Michael`
+        codes.append("K", "X");
+    } else if metaphone_greek_ch(word, index) || metaphone_germanic_k(word, index) {
+        codes.append_both("K");
+    } else if index > 0 {
+        if word.matches(0, &["MC"]) {
+            codes.append_both("K");
+        } else {
+            codes.append("X", "K");
+        }
+    } else {
+        codes.append_both("X");
+    }
+    index + 2
+}
+
+/// Returns whether the CH at the start of the word is Greek, e.g., `Character`.
+fn metaphone_greek_ch(word: &MetaphoneWord, index: isize) -> bool {
+    index == 0
+        && (word.matches(index + 1, &["HARAC", "HARIS"])
+            || word.matches(index + 1, &["HOR", "HYM", "HIA", "HEM"]))
+        && !word.matches(0, &["CHORE"])
+}
+
+/// Returns whether the CH at the given index sounds like K, e.g., `Orchestra` or `Wachtler`.
+fn metaphone_germanic_k(word: &MetaphoneWord, index: isize) -> bool {
+    word.starts_with_van_von_or_sch()
+        || word.matches(index - 2, &["ORCHES", "ARCHIT", "ORCHID"])
+        || word.matches(index + 2, &["T", "S"])
+        || ((word.matches(index - 1, &["A", "O", "U", "E"]) || index == 0)
+            && (word.matches(index + 2, &["L", "R", "N", "M", "B", "H", "F", "V", "W", " "])
+                || index + 1 == word.len() - 1))
+}
+
+fn metaphone_d(word: &MetaphoneWord, codes: &mut MetaphoneCodes, index: isize) -> isize {
+    if word.matches(index, &["DG"]) {
+        if word.matches(index + 2, &["I", "E", "Y"]) {
+            // e.g., `Edge`
+            codes.append_both("J");
+            index + 3
+        } else {
+            // e.g., `Edgar`
+            codes.append_both("TK");
+            index + 2
+        }
+    } else if word.matches(index, &["DT", "DD"]) {
+        codes.append_both("T");
+        index + 2
+    } else {
+        codes.append_both("T");
+        index + 1
+    }
+}
+
+fn metaphone_g(word: &MetaphoneWord, codes: &mut MetaphoneCodes, index: isize) -> isize {
+    let next = word.at(index + 1);
+    if next == 'H' {
+        metaphone_gh(word, codes, index)
+    } else if next == 'N' {
+        if index == 1 && word.is_vowel_at(0) && !word.slavo_germanic {
+            codes.append("KN", "N");
+        } else if !word.matches(index + 2, &["EY"]) && !word.slavo_germanic {
+            codes.append("N", "KN");
+        } else {
+            codes.append_both("KN");
+        }
+        index + 2
+    } else if word.matches(index + 1, &["LI"]) && !word.slavo_germanic {
+        // e.g., `Tagliaro`
+        codes.append("KL", "L");
+        index + 2
+    } else if metaphone_hard_g(word, index) {
+        codes.append("K", "J");
+        index + 2
+    } else if word.matches(index + 1, &["E", "I", "Y"])
+        || word.matches(index - 1, &["AGGI", "OGGI"])
+    {
+        if word.starts_with_van_von_or_sch() || word.matches(index + 1, &["ET"]) {
+            // Germanic
+            codes.append_both("K");
+        } else if word.matches(index + 1, &["IER"]) {
+            codes.append_both("J");
+        } else {
+            codes.append("J", "K");
+        }
+        index + 2
+    } else if next == 'G' {
+        codes.append_both("K");
+        index + 2
+    } else {
+        codes.append_both("K");
+        index + 1
+    }
+}
+
+/// Returns whether the G at the given index may be hard, e.g., `Gilbert` or `Geiger`.
+fn metaphone_hard_g(word: &MetaphoneWord, index: isize) -> bool {
+    let next = word.at(index + 1);
+    let start = index == 0
+        && (next == 'Y'
+            || word.matches(
+                index + 1,
+                &["ES", "EP", "EB", "EL", "EY", "IB", "IL", "IN", "IE", "EI", "ER"],
+            ));
+    start
+        || ((word.matches(index + 1, &["ER"]) || next == 'Y')
+            && !word.matches(0, &["DANGER", "RANGER", "MANGER"])
+            && !word.matches(index - 1, &["E", "I"])
+            && !word.matches(index - 1, &["RGY", "OGY"]))
+}
+
+fn metaphone_gh(word: &MetaphoneWord, codes: &mut MetaphoneCodes, index: isize) -> isize {
+    if index > 0 && !word.is_vowel_at(index - 1) {
+        codes.append_both("K");
+    } else if index == 0 {
+        if word.at(index + 2) == 'I' {
+            codes.append_both("J");
+        } else {
+            codes.append_both("K");
+        }
+    } else if (index > 1 && word.matches(index - 2, &["B", "H", "D"]))
+        || (index > 2 && word.matches(index - 3, &["B", "H", "D"]))
+        || (index > 3 && word.matches(index - 4, &["B", "H"]))
+    {
+        // silent, e.g., `Hugh` or `Bough`
+    } else if index > 2 && word.at(index - 1) == 'U'
+        && word.matches(index - 3, &["C", "G", "L", "R", "T"])
+    {
+        // e.g., `Laugh` or `Tough`
+        codes.append_both("F");
+    } else if index > 0 && word.at(index - 1) != 'I' {
+        codes.append_both("K");
+    }
+    index + 2
+}
+
+fn metaphone_j(word: &MetaphoneWord, codes: &mut MetaphoneCodes, index: isize) -> isize {
+    if word.matches(index, &["JOSE"]) || word.matches(0, &["SAN "]) {
+        // Spanish, e.g., `Jose` or `San Jacinto`
+        if (index == 0 && word.at(index + 4) == ' ') || word.len() == 4
+            || word.matches(0, &["SAN "])
+        {
+            codes.append_both("H");
+        } else {
+            codes.append("J", "H");
+        }
+        return index + 1;
+    }
+    if index == 0 {
+        codes.append("J", "A");
+    } else if word.is_vowel_at(index - 1) && !word.slavo_germanic
+        && (word.at(index + 1) == 'A' || word.at(index + 1) == 'O')
+    {
+        codes.append("J", "H");
+    } else if index == word.len() - 1 {
+        codes.append_primary("J");
+    } else if !word.matches(index + 1, &["L", "T", "K", "S", "N", "M", "B", "Z"])
+        && !word.matches(index - 1, &["S", "K", "L"])
+    {
+        codes.append_both("J");
+    }
+    word.skip(index, "J")
+}
+
+/// Returns whether the LL at the given index is Spanish, e.g., `Cabrillo` or `Gallegos`.
+fn metaphone_spanish_ll(word: &MetaphoneWord, index: isize) -> bool {
+    let len = word.len();
+    (index == len - 3 && word.matches(index - 1, &["ILLO", "ILLA", "ALLE"]))
+        || ((word.matches(len - 2, &["AS", "OS"]) || word.matches(len - 1, &["A", "O"]))
+            && word.matches(index - 1, &["ALLE"]))
+}
+
+fn metaphone_s(word: &MetaphoneWord, codes: &mut MetaphoneCodes, index: isize) -> isize {
+    if word.matches(index - 1, &["ISL", "YSL"]) {
+        // silent, e.g., `Island` or `Carlysle`
+        index + 1
+    } else if index == 0 && word.matches(index, &["SUGAR"]) {
+        codes.append("X", "S");
+        index + 1
+    } else if word.matches(index, &["SH"]) {
+        if word.matches(index + 1, &["HEIM", "HOEK", "HOLM", "HOLZ"]) {
+            // Germanic
+            codes.append_both("S");
+        } else {
+            codes.append_both("X");
+        }
+        index + 2
+    } else if word.matches(index, &["SIO", "SIA"]) || word.matches(index, &["SIAN"]) {
+        // Italian or Armenian
+        if word.slavo_germanic {
+            codes.append_both("S");
+        } else {
+            codes.append("S", "X");
+        }
+        index + 3
+    } else if (index == 0 && word.matches(index + 1, &["M", "N", "L", "W"]))
+        || word.matches(index + 1, &["Z"])
+    {
+        // German and Anglicisations, e.g., `Smith` and `Schmidt`, `Snider` and `Schneider`
+        codes.append("S", "X");
+        word.skip(index, "Z")
+    } else if word.matches(index, &["SC"]) {
+        metaphone_sc(word, codes, index)
+    } else {
+        // the final S of French words, e.g., `Resnais`
+        if index == word.len() - 1 && word.matches(index - 2, &["AI", "OI"]) {
+            codes.append_alternate("S");
+        } else {
+            codes.append_both("S");
+        }
+        word.skip(index, "SZ")
+    }
+}
+
+fn metaphone_sc(word: &MetaphoneWord, codes: &mut MetaphoneCodes, index: isize) -> isize {
+    if word.at(index + 2) == 'H' {
+        if word.matches(index + 3, &["OO", "ER", "EN", "UY", "ED", "EM"]) {
+            // Dutch origin, e.g., `School` or `Schooner`
+            if word.matches(index + 3, &["ER", "EN"]) {
+                // e.g., `Schermerhorn` or `Schenker`
+                codes.append("X", "SK");
+            } else {
+                codes.append_both("SK");
+            }
+        } else if index == 0 && !word.is_vowel_at(3) && word.at(3) != 'W' {
+            codes.append("X", "S");
+        } else {
+            codes.append_both("X");
+        }
+    } else if word.matches(index + 2, &["I", "E", "Y"]) {
+        codes.append_both("S");
+    } else {
+        codes.append_both("SK");
+    }
+    index + 3
+}
+
+fn metaphone_t(word: &MetaphoneWord, codes: &mut MetaphoneCodes, index: isize) -> isize {
+    if word.matches(index, &["TION"]) || word.matches(index, &["TIA", "TCH"]) {
+        codes.append_both("X");
+        index + 3
+    } else if word.matches(index, &["TH"]) || word.matches(index, &["TTH"]) {
+        if word.matches(index + 2, &["OM", "AM"]) || word.starts_with_van_von_or_sch() {
+            // e.g., `Thomas` or `Thames`
+            codes.append_both("T");
+        } else {
+            codes.append("0", "T");
+        }
+        index + 2
+    } else {
+        codes.append_both("T");
+        word.skip(index, "TD")
+    }
+}
+
+fn metaphone_w(word: &MetaphoneWord, codes: &mut MetaphoneCodes, index: isize) -> isize {
+    if word.matches(index, &["WR"]) {
+        codes.append_both("R");
+        index + 2
+    } else if index == 0 && (word.is_vowel_at(index + 1) || word.matches(index, &["WH"])) {
+        if word.is_vowel_at(index + 1) {
+            // e.g., `Wasserman` or `Vasserman`
+            codes.append("A", "F");
+        } else {
+            codes.append_both("A");
+        }
+        index + 1
+    } else if (index == word.len() - 1 && word.is_vowel_at(index - 1))
+        || word.matches(index - 1, &["EWSKI", "EWSKY", "OWSKI", "OWSKY"])
+        || word.matches(0, &["SCH"])
+    {
+        // Polish, e.g., `Filipowicz`
+        codes.append_alternate("F");
+        index + 1
+    } else if word.matches(index, &["WICZ", "WITZ"]) {
+        codes.append("TS", "FX");
+        index + 4
+    } else {
+        index + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(encoder: PhoneticEncoder, words: &[&str]) -> Vec<Vec<String>> {
+        words.iter().map(|word| encoder.encode(word)).collect()
+    }
+
+    #[test]
+    fn test_soundex() {
+        let codes = encode(
+            PhoneticEncoder::Soundex,
+            &["Robert", "Rupert", "Rubin", "Ashcraft", "Tymczak", "Pfister", "Lee", "A", "42"],
+        );
+        let expected: Vec<Vec<&str>> = vec![
+            vec!["R163"],
+            vec!["R163"],
+            vec!["R150"],
+            vec!["A261"],
+            vec!["T522"],
+            vec!["P236"],
+            vec!["L000"],
+            vec!["A000"],
+            vec![],
+        ];
+        assert_eq!(codes, expected);
+    }
+
+    #[test]
+    fn test_refined_soundex() {
+        let codes = encode(
+            PhoneticEncoder::RefinedSoundex,
+            &["Smith", "Smyth", "Schmidt", "Testing", "Braz", "Broz"],
+        );
+        let expected: Vec<Vec<&str>> = vec![
+            vec!["S38060"],
+            vec!["S38060"],
+            vec!["S30806"],
+            vec!["T6036084"],
+            vec!["B1905"],
+            vec!["B1905"],
+        ];
+        assert_eq!(codes, expected);
+    }
+
+    #[test]
+    fn test_double_metaphone() {
+        let codes = encode(
+            PhoneticEncoder::DoubleMetaphone,
+            &[
+                "Smith", "Schmidt", "Thompson", "Jose", "Michael", "Xavier", "Knight", "Laugh",
+                "Caesar", "Gallegos", "Wasserman", "Filipowicz", "Accident", "Edge", "Tagliaro",
+                "Zhao", "42",
+            ],
+        );
+        let expected: Vec<Vec<&str>> = vec![
+            vec!["SM0", "XMT"],
+            vec!["XMT", "SMT"],
+            vec!["TMPS"],
+            vec!["HS"],
+            vec!["MKL", "MXL"],
+            vec!["SF", "SFR"],
+            vec!["NT"],
+            vec!["LF"],
+            vec!["SSR"],
+            vec!["KLKS", "KKS"],
+            vec!["ASRM", "FSRM"],
+            vec!["FLPT", "FLPF"],
+            vec!["AKST"],
+            vec!["AJ"],
+            vec!["TKLR", "TLR"],
+            vec!["J"],
+            vec![],
+        ];
+        assert_eq!(codes, expected);
+    }
+}
@@ -0,0 +1,266 @@
+//! Shingles, i.e., word n-grams, joining adjacent tokens into single ones, e.g., `quick brown fox`
+//! into `quick brown` and `brown fox`.
+//!
+//! A shingle is at the position of its first token and spans the positions of its tokens. Only
+//! adjacent tokens are joined, i.e., a token is followed by the token at the end of the positions
+//! it spans, e.g., a synonym spanning a phrase, so that a gap left by a removed token breaks the
+//! shingles.
+//!
+//! A [`PhraseQuery`][phrase] may look up the shingles of its terms instead of each term, see
+//! [`PhraseQuery::set_shingles`][set_shingles].
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::tokenizer::Tokenizer;
+//! use ::iryfful::tokenizer::filter::TokenFilter;
+//! use ::iryfful::tokenizer::filter::shingles::Shingles;
+//! use ::iryfful::tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+//!
+//! let mut tokenizer = WhiteSpaceTokenizer::new();
+//! tokenizer.add_filter(TokenFilter::Shingles(Shingles::new(2, 2)));
+//!
+//! let tokens: Vec<(u32, String)> = tokenizer
+//!     .tokenize("quick brown fox")
+//!     .map(|token| (token.position, token.token))
+//!     .collect();
+//!
+//! assert_eq!(tokens, vec![
+//!     (1, String::from("quick")),
+//!     (1, String::from("quick brown")),
+//!     (2, String::from("brown")),
+//!     (2, String::from("brown fox")),
+//!     (3, String::from("fox")),
+//! ]);
+//! ```
+//!
+//! [phrase]: ../../../search/query/phrase_query/index.html
+//! [set_shingles]: ../../../search/query/phrase_query/struct.PhraseQuery.html#method.set_shingles
+use std::collections::VecDeque;
+use tokenizer::Token;
+
+/// The configuration of a [`TokenFilter::Shingles`][shingles] filter.
+///
+/// [shingles]: ../enum.TokenFilter.html#variant.Shingles
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Shingles {
+    /// The minimum number of tokens of a shingle, at least 2
+    pub min_size: usize,
+    /// The maximum number of tokens of a shingle
+    pub max_size: usize,
+    /// The string inserted between the tokens of a shingle
+    pub separator: String,
+    /// Whether the original tokens are outputted along with the shingles
+    pub output_unigrams: bool,
+}
+
+impl Default for Shingles {
+    fn default() -> Shingles {
+        Shingles::new(2, 2)
+    }
+}
+
+impl Shingles {
+    /// Creates a configuration for shingles of `min_size` to `max_size` tokens separated by a
+    /// space, outputted along with the original tokens.
+    pub fn new(min_size: usize, max_size: usize) -> Shingles {
+        let min_size = min_size.max(2);
+        Shingles {
+            min_size,
+            max_size: max_size.max(min_size),
+            separator: String::from(" "),
+            output_unigrams: true,
+        }
+    }
+
+    /// Returns the shingle of the given words.
+    pub fn join<S: AsRef<str>>(&self, words: &[S]) -> String {
+        let mut shingle = String::new();
+        for (i, word) in words.iter().enumerate() {
+            if i > 0 {
+                shingle.push_str(&self.separator);
+            }
+            shingle.push_str(word.as_ref());
+        }
+        shingle
+    }
+
+    /// Returns the given stream of [`Token`]s along with their shingles.
+    pub fn filter<'a>(
+        &'a self,
+        tokens: Box<Iterator<Item = Token> + 'a>,
+    ) -> Box<Iterator<Item = Token> + 'a> {
+        Box::new(ShingleStream {
+            tokens,
+            shingles: self,
+            input: VecDeque::new(),
+            output: VecDeque::new(),
+        })
+    }
+}
+
+struct ShingleStream<'a> {
+    tokens: Box<Iterator<Item = Token> + 'a>,
+    shingles: &'a Shingles,
+    /// The tokens read ahead to build the shingles of the first one
+    input: VecDeque<Token>,
+    /// The tokens to return, all at the position of the last token removed from the input
+    output: VecDeque<Token>,
+}
+
+/// Returns the tokens following each other from the first of the given ones, i.e., the first
+/// token at the end of the positions spanned by the previous one, up to `max` tokens.
+fn adjacent<'t, I: Iterator<Item = &'t Token>>(mut tokens: I, max: usize) -> Vec<&'t Token> {
+    let mut words: Vec<&Token> = tokens.next().into_iter().collect();
+    for token in tokens {
+        if words.len() == max {
+            break;
+        }
+        let last = words[words.len() - 1];
+        if token.position == last.position + last.position_length {
+            words.push(token);
+        }
+    }
+    words
+}
+
+impl<'a> ShingleStream<'a> {
+    /// Reads tokens until the input holds the tokens of the largest shingle of the first token.
+    fn fill_input(&mut self) {
+        loop {
+            if let Some(last) = self.input.back() {
+                let words = adjacent(self.input.iter(), self.shingles.max_size);
+                let word = words[words.len() - 1];
+                // the tokens are sorted, so that a token past the next word ends the shingle
+                if words.len() == self.shingles.max_size
+                    || last.position > word.position + word.position_length
+                {
+                    return;
+                }
+            }
+            match self.tokens.next() {
+                Some(token) => self.input.push_back(token),
+                None => return,
+            }
+        }
+    }
+
+    /// Fills the output with the first token of the input and the shingles it starts.
+    fn shingle(&mut self) {
+        let words = adjacent(self.input.iter(), self.shingles.max_size);
+        let first = words[0];
+        for size in self.shingles.min_size..=words.len() {
+            let last = words[size - 1];
+            let tokens: Vec<&str> = words[..size]
+                .iter()
+                .map(|token| token.token.as_str())
+                .collect();
+            self.output.push_back(Token {
+                token: self.shingles.join(&tokens),
+                position: first.position,
+                position_length: last.position + last.position_length - first.position,
+                start_offset: first.start_offset,
+                end_offset: last.end_offset,
+                keyword: false,
+            });
+        }
+        let first = self.input.pop_front().unwrap();
+        if self.shingles.output_unigrams {
+            self.output.push_front(first);
+        }
+    }
+}
+
+impl<'a> Iterator for ShingleStream<'a> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        while self.output.is_empty() {
+            self.fill_input();
+            if self.input.is_empty() {
+                return None;
+            }
+            self.shingle();
+        }
+        self.output.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokenizer::Tokenizer;
+    use tokenizer::filter::TokenFilter;
+    use tokenizer::filter::synonyms::SynonymMap;
+    use tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+
+    fn tokens(shingles: Shingles, input: &str) -> Vec<(u32, u32, String)> {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::StopWords(
+            vec![String::from("the")].into_iter().collect(),
+        ));
+        tokenizer.add_filter(TokenFilter::Shingles(shingles));
+        tokenizer
+            .tokenize(input)
+            .map(|token| (token.position, token.position_length, token.token))
+            .collect()
+    }
+
+    #[test]
+    fn test_shingle_sizes() {
+        let mut shingles = Shingles::new(2, 3);
+        shingles.separator = String::from("_");
+        shingles.output_unigrams = false;
+
+        assert_eq!(
+            tokens(shingles, "aaa bbb ccc ddd"),
+            vec![
+                (1, 2, String::from("aaa_bbb")),
+                (1, 3, String::from("aaa_bbb_ccc")),
+                (2, 2, String::from("bbb_ccc")),
+                (2, 3, String::from("bbb_ccc_ddd")),
+                (3, 2, String::from("ccc_ddd")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gaps() {
+        assert_eq!(
+            tokens(Shingles::new(2, 2), "aaa the bbb ccc"),
+            vec![
+                (1, 1, String::from("aaa")),
+                (3, 1, String::from("bbb")),
+                (3, 2, String::from("bbb ccc")),
+                (4, 1, String::from("ccc")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multi_position_tokens() {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::Synonyms(
+            SynonymMap::parse("new york city => nyc, big apple").unwrap(),
+        ));
+        let mut shingles = Shingles::new(2, 2);
+        shingles.output_unigrams = false;
+        tokenizer.add_filter(TokenFilter::Shingles(shingles));
+
+        let tokens: Vec<(u32, u32, String)> = tokenizer
+            .tokenize("new york city hall")
+            .map(|token| (token.position, token.position_length, token.token))
+            .collect();
+
+        // nyc spans the positions of new york city, and apple those of york city
+        assert_eq!(
+            tokens,
+            vec![
+                (1, 4, String::from("nyc hall")),
+                (1, 3, String::from("big apple")),
+                (2, 3, String::from("apple hall")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! The Snowball stemming algorithm for French.
+use super::snowball::{ends_with, go_past, longest_suffix, longest_suffix_from, region, replace,
+                      start_of};
+
+fn is_vowel(c: char) -> bool {
+    "aeiouyâàëéêèïîôûù".contains(c)
+}
+
+/// Marks as consonants the vowels `u` and `i` between vowels, `y` next to a vowel and `u` after
+/// `q`, by putting them in upper case.
+fn mark_consonants(word: &mut [char]) {
+    for i in 0..word.len().saturating_sub(1) {
+        let next = word[i + 1];
+        if is_vowel(word[i]) && ['u', 'i'].contains(&next) && i + 2 < word.len()
+            && is_vowel(word[i + 2])
+        {
+            word[i + 1] = next.to_ascii_uppercase();
+        } else if is_vowel(word[i]) && next == 'y' {
+            word[i + 1] = 'Y';
+        } else if word[i] == 'y' && is_vowel(next) {
+            word[i] = 'Y';
+        } else if word[i] == 'q' && next == 'u' {
+            word[i + 1] = 'U';
+        }
+    }
+}
+
+/// Returns the start of the region RV.
+fn rv(word: &[char]) -> usize {
+    if word.len() >= 3 && is_vowel(word[0]) && is_vowel(word[1]) {
+        return 3;
+    }
+    if ["par", "col", "tap"].iter().any(|prefix| {
+        let prefix: Vec<char> = prefix.chars().collect();
+        word.starts_with(&prefix)
+    }) {
+        return 3;
+    }
+    go_past(word, 1, is_vowel).unwrap_or(word.len())
+}
+
+/// Removes a standard suffix, returning `true` if one was removed.
+///
+/// The `-ment` suffixes are not reported as removed, so that the verb suffixes they typically
+/// follow are looked for.
+fn standard_suffix(word: &mut Vec<char>, rv: usize, p1: usize, p2: usize) -> bool {
+    let suffixes = &[
+        "ance", "iqUe", "isme", "able", "iste", "eux", "ances", "iqUes", "ismes", "ables",
+        "istes", "atrice", "ateur", "ation", "atrices", "ateurs", "ations", "logie", "logies",
+        "usion", "ution", "usions", "utions", "ence", "ences", "ement", "ements", "ité", "ités",
+        "if", "ive", "ifs", "ives", "eaux", "aux", "euse", "euses", "issement", "issements",
+        "amment", "emment", "ment", "ments",
+    ];
+    let suffix = match longest_suffix(word, suffixes) {
+        Some(suffix) => suffix,
+        None => return false,
+    };
+    let start = start_of(word, suffix);
+    match suffix {
+        "eaux" => replace(word, suffix, "eau"),
+        "aux" if start >= p1 => replace(word, suffix, "al"),
+        "euse" | "euses" if start >= p2 => word.truncate(start),
+        "euse" | "euses" if start >= p1 => replace(word, suffix, "eux"),
+        "issement" | "issements" if start >= p1 && start >= 1 && !is_vowel(word[start - 1]) => {
+            word.truncate(start)
+        }
+        "amment" | "emment" | "ment" | "ments" => {
+            match suffix {
+                "amment" if start >= rv => replace(word, suffix, "ant"),
+                "emment" if start >= rv => replace(word, suffix, "ent"),
+                "ment" | "ments" if start > rv && is_vowel(word[start - 1]) => {
+                    word.truncate(start)
+                }
+                _ => {}
+            }
+            return false;
+        }
+        "ement" | "ements" if start >= rv => {
+            word.truncate(start);
+            match longest_suffix(word, &["iv", "eus", "abl", "iqU", "ièr", "Ièr"]) {
+                Some("iv") if start_of(word, "iv") >= p2 => {
+                    replace(word, "iv", "");
+                    if ends_with(word, "at") && start_of(word, "at") >= p2 {
+                        replace(word, "at", "");
+                    }
+                }
+                Some("eus") if start_of(word, "eus") >= p2 => replace(word, "eus", ""),
+                Some("eus") if start_of(word, "eus") >= p1 => replace(word, "eus", "eux"),
+                Some(preceding @ "abl") | Some(preceding @ "iqU")
+                    if start_of(word, preceding) >= p2 =>
+                {
+                    replace(word, preceding, "")
+                }
+                Some(preceding @ "ièr") | Some(preceding @ "Ièr")
+                    if start_of(word, preceding) >= rv =>
+                {
+                    replace(word, preceding, "i")
+                }
+                _ => {}
+            }
+        }
+        "ité" | "ités" if start >= p2 => {
+            word.truncate(start);
+            match longest_suffix(word, &["abil", "ic", "iv"]) {
+                Some(preceding) if start_of(word, preceding) >= p2 => replace(word, preceding, ""),
+                Some("abil") => replace(word, "abil", "abl"),
+                Some("ic") => replace(word, "ic", "iqU"),
+                _ => {}
+            }
+        }
+        "if" | "ive" | "ifs" | "ives" if start >= p2 => {
+            word.truncate(start);
+            if ends_with(word, "at") && start_of(word, "at") >= p2 {
+                replace(word, "at", "");
+                remove_ic(word, p2);
+            }
+        }
+        "atrice" | "ateur" | "ation" | "atrices" | "ateurs" | "ations" if start >= p2 => {
+            word.truncate(start);
+            remove_ic(word, p2);
+        }
+        "logie" | "logies" if start >= p2 => replace(word, suffix, "log"),
+        "usion" | "ution" | "usions" | "utions" if start >= p2 => replace(word, suffix, "u"),
+        "ence" | "ences" if start >= p2 => replace(word, suffix, "ent"),
+        "ance" | "iqUe" | "isme" | "able" | "iste" | "eux" | "ances" | "iqUes" | "ismes"
+        | "ables" | "istes" if start >= p2 => word.truncate(start),
+        _ => return false,
+    }
+    true
+}
+
+/// Removes a preceding `-ic` in R2, or replaces it with `-iqU`.
+fn remove_ic(word: &mut Vec<char>, p2: usize) {
+    if ends_with(word, "ic") {
+        if start_of(word, "ic") >= p2 {
+            replace(word, "ic", "");
+        } else {
+            replace(word, "ic", "iqU");
+        }
+    }
+}
+
+/// Removes a verb suffix beginning with `i` in RV, returning `true` if one was removed.
+fn i_verb_suffix(word: &mut Vec<char>, rv: usize) -> bool {
+    let suffixes = &[
+        "îmes", "ît", "îtes", "i", "ie", "ies", "ir", "ira", "irai", "iraIent", "irais", "irait",
+        "iras", "irent", "irez", "iriez", "irions", "irons", "iront", "is", "issaIent", "issais",
+        "issait", "issant", "issante", "issantes", "issants", "isse", "issent", "isses", "issez",
+        "issiez", "issions", "issons", "it",
+    ];
+    match longest_suffix_from(word, rv, suffixes) {
+        Some(suffix) => {
+            let start = start_of(word, suffix);
+            if start > rv && !is_vowel(word[start - 1]) {
+                word.truncate(start);
+                true
+            } else {
+                false
+            }
+        }
+        None => false,
+    }
+}
+
+/// Removes another verb suffix in RV, returning `true` if one was removed.
+fn verb_suffix(word: &mut Vec<char>, rv: usize, p2: usize) -> bool {
+    let suffixes = &[
+        "ions", "é", "ée", "ées", "és", "èrent", "er", "era", "erai", "eraIent", "erais", "erait",
+        "eras", "erez", "eriez", "erions", "erons", "eront", "ez", "iez", "âmes", "ât", "âtes", "a",
+        "ai", "aIent", "ais", "ait", "ant", "ante", "antes", "ants", "as", "asse", "assent",
+        "asses", "assiez", "assions",
+    ];
+    let suffix = match longest_suffix_from(word, rv, suffixes) {
+        Some(suffix) => suffix,
+        None => return false,
+    };
+    let start = start_of(word, suffix);
+    if suffix == "ions" && start < p2 {
+        return false;
+    }
+    word.truncate(start);
+    if (suffix.starts_with('a') || suffix.starts_with('â')) && ends_with(word, "e")
+        && start_of(word, "e") >= rv
+    {
+        word.pop();
+    }
+    true
+}
+
+/// Removes a residual suffix.
+fn residual_suffix(word: &mut Vec<char>, rv: usize, p2: usize) {
+    if word.len() >= 2 && word[word.len() - 1] == 's' && !"aiouès".contains(word[word.len() - 2])
+    {
+        word.pop();
+    }
+    let suffixes = &["ion", "ier", "ière", "Ier", "Ière", "e", "ë"];
+    if let Some(suffix) = longest_suffix_from(word, rv, suffixes) {
+        let start = start_of(word, suffix);
+        match suffix {
+            "ion" => if start >= p2 && start > rv && ['s', 't'].contains(&word[start - 1]) {
+                word.truncate(start);
+            },
+            "e" => word.truncate(start),
+            "ë" => if start >= rv + 2 && word[start - 2..start] == ['g', 'u'] {
+                word.truncate(start);
+            },
+            _ => replace(word, suffix, "i"),
+        }
+    }
+}
+
+/// Returns the stem of the given lowercased word.
+pub fn stem(word: &str) -> String {
+    let mut word: Vec<char> = word.chars().collect();
+    mark_consonants(&mut word);
+    let rv = rv(&word);
+    let p1 = region(&word, 0, is_vowel);
+    let p2 = region(&word, p1, is_vowel);
+
+    if standard_suffix(&mut word, rv, p1, p2) || i_verb_suffix(&mut word, rv)
+        || verb_suffix(&mut word, rv, p2)
+    {
+        match word.last() {
+            Some(&'Y') => *word.last_mut().unwrap() = 'i',
+            Some(&'ç') => *word.last_mut().unwrap() = 'c',
+            _ => {}
+        }
+    } else {
+        residual_suffix(&mut word, rv, p2);
+    }
+
+    // undouble
+    if ["enn", "onn", "ett", "ell", "eill"]
+        .iter()
+        .any(|suffix| ends_with(&word, suffix))
+    {
+        word.pop();
+    }
+
+    // unaccent an e followed by consonants
+    let end = word.len() - word.iter().rev().take_while(|&&c| !is_vowel(c)).count();
+    if end >= 1 && end < word.len() && (word[end - 1] == 'é' || word[end - 1] == 'è') {
+        word[end - 1] = 'e';
+    }
+
+    word.into_iter()
+        .map(|c| match c {
+            'I' => 'i',
+            'U' => 'u',
+            'Y' => 'y',
+            c => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stem() {
+        let words = [
+            ("continuellement", "continuel"),
+            ("continuation", "continu"),
+            ("chevaux", "cheval"),
+            ("heureusement", "heureux"),
+            ("finissions", "fin"),
+            ("abondamment", "abond"),
+            ("majestueuse", "majestu"),
+            ("à", "à"),
+        ];
+        for &(word, expected) in words.iter() {
+            assert_eq!(stem(word), expected, "stemming {:?}", word);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! The Snowball stemming algorithm for German.
+use super::snowball::{ends_with, longest_suffix, region, replace, start_of};
+
+fn is_vowel(c: char) -> bool {
+    "aeiouyäöü".contains(c)
+}
+
+/// Returns `true` if the character may precede a removed `-s`.
+fn is_s_ending(c: char) -> bool {
+    "bdfghklmnrt".contains(c)
+}
+
+/// Returns `true` if the character may precede a removed `-st`.
+fn is_st_ending(c: char) -> bool {
+    "bdfghklmnt".contains(c)
+}
+
+/// Returns `true` if the character preceding the index is not an `e`.
+fn not_after_e(word: &[char], i: usize) -> bool {
+    i == 0 || word[i - 1] != 'e'
+}
+
+/// Returns the stem of the given lowercased word.
+pub fn stem(word: &str) -> String {
+    let mut word: Vec<char> = word.replace('ß', "ss").chars().collect();
+
+    // protect u and y between vowels from being considered as vowels
+    for i in 0..word.len().saturating_sub(2) {
+        if is_vowel(word[i]) && is_vowel(word[i + 2]) && ['u', 'y'].contains(&word[i + 1]) {
+            word[i + 1] = word[i + 1].to_ascii_uppercase();
+        }
+    }
+
+    // R1 is adjusted so that at least 3 letters precede it
+    let (p1, p2) = if word.len() < 3 {
+        (word.len(), word.len())
+    } else {
+        let p1 = region(&word, 0, is_vowel);
+        (p1.max(3), region(&word, p1, is_vowel))
+    };
+
+    // step 1
+    let suffixes = &["em", "ern", "er", "e", "en", "es", "s"];
+    if let Some(suffix) = longest_suffix(&word, suffixes) {
+        let start = start_of(&word, suffix);
+        if start >= p1 {
+            match suffix {
+                "s" => if start >= 1 && is_s_ending(word[start - 1]) {
+                    word.truncate(start);
+                },
+                "e" | "en" | "es" => {
+                    word.truncate(start);
+                    if ends_with(&word, "niss") {
+                        word.pop();
+                    }
+                }
+                _ => word.truncate(start),
+            }
+        }
+    }
+
+    // step 2
+    if let Some(suffix) = longest_suffix(&word, &["en", "er", "est", "st"]) {
+        let start = start_of(&word, suffix);
+        if start >= p1 {
+            match suffix {
+                "st" => if start >= 4 && is_st_ending(word[start - 1]) {
+                    word.truncate(start);
+                },
+                _ => word.truncate(start),
+            }
+        }
+    }
+
+    // step 3
+    let suffixes = &["end", "ung", "ig", "ik", "isch", "lich", "heit", "keit"];
+    if let Some(suffix) = longest_suffix(&word, suffixes) {
+        let start = start_of(&word, suffix);
+        if start >= p2 {
+            match suffix {
+                "end" | "ung" => {
+                    word.truncate(start);
+                    if ends_with(&word, "ig") {
+                        let start = start_of(&word, "ig");
+                        if start >= p2 && not_after_e(&word, start) {
+                            word.truncate(start);
+                        }
+                    }
+                }
+                "ig" | "ik" | "isch" => if not_after_e(&word, start) {
+                    word.truncate(start);
+                },
+                "lich" | "heit" => {
+                    word.truncate(start);
+                    if let Some(suffix) = longest_suffix(&word, &["er", "en"]) {
+                        if start_of(&word, suffix) >= p1 {
+                            replace(&mut word, suffix, "");
+                        }
+                    }
+                }
+                _ => {
+                    word.truncate(start);
+                    if let Some(suffix) = longest_suffix(&word, &["lich", "ig"]) {
+                        if start_of(&word, suffix) >= p2 {
+                            replace(&mut word, suffix, "");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    word.into_iter()
+        .map(|c| match c {
+            'U' | 'ü' => 'u',
+            'Y' => 'y',
+            'ä' => 'a',
+            'ö' => 'o',
+            c => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stem() {
+        let words = [
+            ("aufeinanderfolgenden", "aufeinanderfolg"),
+            ("häuser", "haus"),
+            ("schönheit", "schonheit"),
+            ("straße", "strass"),
+            ("bauen", "bau"),
+            ("kenntnisse", "kenntnis"),
+            ("ab", "ab"),
+        ];
+        for &(word, expected) in words.iter() {
+            assert_eq!(stem(word), expected, "stemming {:?}", word);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Stemming algorithms, reducing inflected words to their stem, e.g., `indexing` and `indexed` to
+//! `index`.
+//!
+//! The algorithms expect lowercased words, so a [`Stemmer`] filter is meant to follow a
+//! [`TokenFilter::LowerCase`][lowercase] filter.
+//!
+//! [lowercase]: ../enum.TokenFilter.html#variant.LowerCase
+mod french;
+mod german;
+mod porter;
+mod snowball;
+mod spanish;
+
+/// A stemming algorithm.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stemmer {
+    /// The Porter algorithm for English
+    Porter,
+    /// The Snowball algorithm for French
+    French,
+    /// The Snowball algorithm for German
+    German,
+    /// The Snowball algorithm for Spanish
+    Spanish,
+}
+
+impl Stemmer {
+    /// Returns the stem of the given word.
+    pub fn stem(&self, word: &str) -> String {
+        match *self {
+            Stemmer::Porter => porter::stem(word),
+            Stemmer::French => french::stem(word),
+            Stemmer::German => german::stem(word),
+            Stemmer::Spanish => spanish::stem(word),
+        }
+    }
+}
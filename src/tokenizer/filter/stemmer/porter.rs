@@ -0,0 +1,288 @@
+//! The Porter stemming algorithm for English.
+//!
+//! This follows the reference implementation by Martin Porter, including its departures from the
+//! published algorithm, e.g., `-bli` is replaced by `-ble` and `-logi` by `-log`.
+
+struct Word {
+    b: Vec<char>,
+    /// The length of the stem preceding the last suffix found with [`Word::ends`]
+    j: usize,
+}
+
+impl Word {
+    /// Returns `true` if the character at the index is a consonant.
+    fn cons(&self, i: usize) -> bool {
+        match self.b[i] {
+            'a' | 'e' | 'i' | 'o' | 'u' => false,
+            'y' => i == 0 || !self.cons(i - 1),
+            _ => true,
+        }
+    }
+
+    /// Returns the number of vowel-consonant sequences in the stem.
+    fn m(&self) -> usize {
+        let mut n = 0;
+        let mut i = 0;
+        // skip the leading consonants
+        while i < self.j && self.cons(i) {
+            i += 1;
+        }
+        loop {
+            while i < self.j && !self.cons(i) {
+                i += 1;
+            }
+            if i >= self.j {
+                return n;
+            }
+            while i < self.j && self.cons(i) {
+                i += 1;
+            }
+            n += 1;
+        }
+    }
+
+    /// Returns `true` if the stem contains a vowel.
+    fn vowel_in_stem(&self) -> bool {
+        (0..self.j).any(|i| !self.cons(i))
+    }
+
+    /// Returns `true` if the characters at the index and before it are the same consonant.
+    fn double_cons(&self, i: usize) -> bool {
+        i >= 1 && self.b[i] == self.b[i - 1] && self.cons(i)
+    }
+
+    /// Returns `true` if the characters up to the index are consonant-vowel-consonant, and the
+    /// last consonant is not `w`, `x` or `y`.
+    fn cvc(&self, i: usize) -> bool {
+        i >= 2 && self.cons(i) && !self.cons(i - 1) && self.cons(i - 2)
+            && !['w', 'x', 'y'].contains(&self.b[i])
+    }
+
+    fn last(&self) -> Option<char> {
+        self.b.last().cloned()
+    }
+
+    /// Returns `true` if the word ends with the suffix, in which case the stem is the part of the
+    /// word preceding it.
+    fn ends(&mut self, suffix: &str) -> bool {
+        let n = suffix.chars().count();
+        if n > self.b.len() || !self.b[self.b.len() - n..].iter().cloned().eq(suffix.chars()) {
+            return false;
+        }
+        self.j = self.b.len() - n;
+        true
+    }
+
+    /// Replaces the last suffix found with the given one.
+    fn set_to(&mut self, suffix: &str) {
+        self.b.truncate(self.j);
+        self.b.extend(suffix.chars());
+    }
+
+    /// Replaces the first of the suffixes the word ends with, if its stem has at least one
+    /// vowel-consonant sequence.
+    fn replace(&mut self, rules: &[(&str, &str)]) {
+        for &(suffix, replacement) in rules {
+            if self.ends(suffix) {
+                if self.m() > 0 {
+                    self.set_to(replacement);
+                }
+                return;
+            }
+        }
+    }
+
+    /// Removes plurals and `-ed` or `-ing`.
+    fn step1ab(&mut self) {
+        if self.last() == Some('s') {
+            if self.ends("sses") {
+                let len = self.b.len();
+                self.b.truncate(len - 2);
+            } else if self.ends("ies") {
+                self.set_to("i");
+            } else if self.b[self.b.len() - 2] != 's' {
+                self.b.pop();
+            }
+        }
+        if self.ends("eed") {
+            if self.m() > 0 {
+                self.b.pop();
+            }
+        } else if (self.ends("ed") || self.ends("ing")) && self.vowel_in_stem() {
+            let j = self.j;
+            self.b.truncate(j);
+            if self.ends("at") {
+                self.set_to("ate");
+            } else if self.ends("bl") {
+                self.set_to("ble");
+            } else if self.ends("iz") {
+                self.set_to("ize");
+            } else if self.double_cons(self.b.len() - 1) {
+                match self.last() {
+                    Some('l') | Some('s') | Some('z') => {}
+                    _ => {
+                        self.b.pop();
+                    }
+                }
+            } else {
+                self.j = self.b.len();
+                if self.m() == 1 && self.cvc(self.b.len() - 1) {
+                    self.set_to("e");
+                }
+            }
+        }
+    }
+
+    /// Turns a terminal `y` into `i` if there is another vowel in the stem.
+    fn step1c(&mut self) {
+        if self.ends("y") && self.vowel_in_stem() {
+            let last = self.b.len() - 1;
+            self.b[last] = 'i';
+        }
+    }
+
+    /// Maps double suffixes to single ones.
+    fn step2(&mut self) {
+        self.replace(&[
+            ("ational", "ate"),
+            ("tional", "tion"),
+            ("enci", "ence"),
+            ("anci", "ance"),
+            ("izer", "ize"),
+            ("bli", "ble"),
+            ("alli", "al"),
+            ("entli", "ent"),
+            ("eli", "e"),
+            ("ousli", "ous"),
+            ("ization", "ize"),
+            ("ation", "ate"),
+            ("ator", "ate"),
+            ("alism", "al"),
+            ("iveness", "ive"),
+            ("fulness", "ful"),
+            ("ousness", "ous"),
+            ("aliti", "al"),
+            ("iviti", "ive"),
+            ("biliti", "ble"),
+            ("logi", "log"),
+        ]);
+    }
+
+    /// Handles `-ic-`, `-full`, `-ness`, etc.
+    fn step3(&mut self) {
+        self.replace(&[
+            ("icate", "ic"),
+            ("ative", ""),
+            ("alize", "al"),
+            ("iciti", "ic"),
+            ("ical", "ic"),
+            ("ful", ""),
+            ("ness", ""),
+        ]);
+    }
+
+    /// Removes `-ant`, `-ence`, etc., in a context with at least two vowel-consonant sequences.
+    fn step4(&mut self) {
+        const SUFFIXES: &[&str] = &[
+            "al", "ance", "ence", "er", "ic", "able", "ible", "ant", "ement", "ment", "ent", "ion",
+            "ou", "ism", "ate", "iti", "ous", "ive", "ize",
+        ];
+        for suffix in SUFFIXES {
+            if self.ends(suffix) {
+                if *suffix == "ion" && !(self.j >= 1 && ['s', 't'].contains(&self.b[self.j - 1]))
+                {
+                    return;
+                }
+                if self.m() > 1 {
+                    let j = self.j;
+                    self.b.truncate(j);
+                }
+                return;
+            }
+        }
+    }
+
+    /// Removes a final `-e` and changes `-ll` to `-l`, in a context with enough vowel-consonant
+    /// sequences.
+    fn step5(&mut self) {
+        self.j = self.b.len();
+        if self.last() == Some('e') {
+            let m = self.m();
+            if m > 1 || m == 1 && !self.cvc(self.b.len() - 2) {
+                self.b.pop();
+                self.j = self.b.len();
+            }
+        }
+        if self.last() == Some('l') && self.double_cons(self.b.len() - 1) && self.m() > 1 {
+            self.b.pop();
+        }
+    }
+}
+
+/// Returns the stem of the given lowercased word.
+pub fn stem(word: &str) -> String {
+    let mut word = Word {
+        b: word.chars().collect(),
+        j: 0,
+    };
+    if word.b.len() <= 2 {
+        return word.b.into_iter().collect();
+    }
+    word.step1ab();
+    if word.b.len() > 1 {
+        word.step1c();
+        word.step2();
+        word.step3();
+        word.step4();
+        word.step5();
+    }
+    word.b.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stem() {
+        let words = [
+            ("caresses", "caress"),
+            ("ponies", "poni"),
+            ("ties", "ti"),
+            ("caress", "caress"),
+            ("cats", "cat"),
+            ("feed", "feed"),
+            ("agreed", "agre"),
+            ("plastered", "plaster"),
+            ("bled", "bled"),
+            ("motoring", "motor"),
+            ("sing", "sing"),
+            ("conflated", "conflat"),
+            ("troubled", "troubl"),
+            ("sized", "size"),
+            ("hopping", "hop"),
+            ("tanned", "tan"),
+            ("falling", "fall"),
+            ("hissing", "hiss"),
+            ("fizzed", "fizz"),
+            ("failing", "fail"),
+            ("filing", "file"),
+            ("happy", "happi"),
+            ("sky", "sky"),
+            ("relational", "relat"),
+            ("generalizations", "gener"),
+            ("oscillators", "oscil"),
+            ("indexing", "index"),
+            ("indexed", "index"),
+            ("is", "is"),
+            // a final -e removed before a double l
+            ("belle", "bell"),
+            ("gazelle", "gazel"),
+            ("bille", "bill"),
+            ("alle", "all"),
+        ];
+        for &(word, expected) in words.iter() {
+            assert_eq!(stem(word), expected, "stemming {:?}", word);
+        }
+    }
+}
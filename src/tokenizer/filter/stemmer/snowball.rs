@@ -0,0 +1,63 @@
+//! Helpers shared by the stemmers of the Snowball family.
+//!
+//! A word is handled as a list of characters, from which suffixes are removed or replaced.
+
+/// Returns `true` if the word ends with the given suffix.
+pub fn ends_with(word: &[char], suffix: &str) -> bool {
+    let n = suffix.chars().count();
+    n <= word.len() && word[word.len() - n..].iter().cloned().eq(suffix.chars())
+}
+
+/// Returns the longest of the given suffixes the word ends with.
+pub fn longest_suffix<'s>(word: &[char], suffixes: &[&'s str]) -> Option<&'s str> {
+    longest_suffix_from(word, 0, suffixes)
+}
+
+/// Returns the longest of the given suffixes the word ends with, and which starts at `from` or
+/// after it, i.e., within a region of the word.
+pub fn longest_suffix_from<'s>(
+    word: &[char],
+    from: usize,
+    suffixes: &[&'s str],
+) -> Option<&'s str> {
+    suffixes
+        .iter()
+        .filter(|suffix| ends_with(word, suffix) && start_of(word, suffix) >= from)
+        .max_by_key(|suffix| suffix.chars().count())
+        .cloned()
+}
+
+/// Returns the index of the first character of the suffix ending the word.
+pub fn start_of(word: &[char], suffix: &str) -> usize {
+    word.len() - suffix.chars().count()
+}
+
+/// Replaces the suffix ending the word with the replacement.
+pub fn replace(word: &mut Vec<char>, suffix: &str, replacement: &str) {
+    let start = start_of(word, suffix);
+    word.truncate(start);
+    word.extend(replacement.chars());
+}
+
+/// Returns the index following the first character matching the predicate, starting at `from`.
+pub fn go_past<F>(word: &[char], from: usize, predicate: F) -> Option<usize>
+where
+    F: Fn(char) -> bool,
+{
+    (from..word.len())
+        .find(|&i| predicate(word[i]))
+        .map(|i| i + 1)
+}
+
+/// Returns the start of the region following the first non-vowel following a vowel, starting at
+/// `from`, or the length of the word if there is none.
+///
+/// The regions `R1` and `R2` of the Snowball stemmers are defined this way.
+pub fn region<F>(word: &[char], from: usize, is_vowel: F) -> usize
+where
+    F: Fn(char) -> bool,
+{
+    go_past(word, from, &is_vowel)
+        .and_then(|i| go_past(word, i, |c| !is_vowel(c)))
+        .unwrap_or(word.len())
+}
@@ -0,0 +1,201 @@
+//! The Snowball stemming algorithm for Spanish.
+use super::snowball::{ends_with, go_past, longest_suffix, longest_suffix_from, region, replace,
+                      start_of};
+
+fn is_vowel(c: char) -> bool {
+    "aeiouáéíóúü".contains(c)
+}
+
+/// Returns the start of the region RV.
+fn rv(word: &[char]) -> usize {
+    if word.len() < 2 {
+        return word.len();
+    }
+    let rv = match (is_vowel(word[0]), is_vowel(word[1])) {
+        (true, true) => go_past(word, 2, |c| !is_vowel(c)),
+        (false, true) => Some(3),
+        _ => go_past(word, 2, is_vowel),
+    };
+    rv.unwrap_or(word.len()).min(word.len())
+}
+
+/// Removes a pronoun attached to a verb.
+fn attached_pronoun(word: &mut Vec<char>, rv: usize) {
+    let pronouns = &[
+        "me", "se", "sela", "selo", "selas", "selos", "la", "le", "lo", "las", "les", "los", "nos",
+    ];
+    let pronoun = match longest_suffix(word, pronouns) {
+        Some(pronoun) => pronoun,
+        None => return,
+    };
+    let verb_len = start_of(word, pronoun);
+    let endings = &[
+        "iéndo", "ándo", "ár", "ér", "ír", "ando", "iendo", "ar", "er", "ir", "yendo",
+    ];
+    let ending = match longest_suffix(&word[..verb_len], endings) {
+        Some(ending) => ending,
+        None => return,
+    };
+    let start = start_of(&word[..verb_len], ending);
+    if start < rv {
+        return;
+    }
+    match ending {
+        "yendo" if start == 0 || word[start - 1] != 'u' => {}
+        "iéndo" | "ándo" | "ár" | "ér" | "ír" => {
+            word.truncate(start);
+            word.extend(ending.chars().map(remove_accent));
+        }
+        _ => word.truncate(verb_len),
+    }
+}
+
+/// Removes a standard suffix, returning `true` if one was removed.
+fn standard_suffix(word: &mut Vec<char>, p1: usize, p2: usize) -> bool {
+    let suffixes = &[
+        "anza", "anzas", "ico", "ica", "icos", "icas", "ismo", "ismos", "able", "ables", "ible",
+        "ibles", "ista", "istas", "oso", "osa", "osos", "osas", "amiento", "amientos", "imiento",
+        "imientos", "adora", "ador", "ación", "adoras", "adores", "aciones", "ante", "antes",
+        "ancia", "ancias", "logía", "logías", "ución", "uciones", "encia", "encias", "amente",
+        "mente", "idad", "idades", "iva", "ivo", "ivas", "ivos",
+    ];
+    let suffix = match longest_suffix(word, suffixes) {
+        Some(suffix) => suffix,
+        None => return false,
+    };
+    let start = start_of(word, suffix);
+    let region = if suffix == "amente" { p1 } else { p2 };
+    if start < region {
+        return false;
+    }
+    match suffix {
+        "logía" | "logías" => replace(word, suffix, "log"),
+        "ución" | "uciones" => replace(word, suffix, "u"),
+        "encia" | "encias" => replace(word, suffix, "ente"),
+        _ => word.truncate(start),
+    }
+    let preceding: &[&str] = match suffix {
+        "adora" | "ador" | "ación" | "adoras" | "adores" | "aciones" | "ante" | "antes"
+        | "ancia" | "ancias" => &["ic"],
+        "amente" => &["iv", "os", "ic", "ad"],
+        "mente" => &["ante", "able", "ible"],
+        "idad" | "idades" => &["abil", "ic", "iv"],
+        "iva" | "ivo" | "ivas" | "ivos" => &["at"],
+        _ => &[],
+    };
+    if let Some(preceding) = longest_suffix(word, preceding) {
+        if start_of(word, preceding) >= p2 {
+            replace(word, preceding, "");
+            if suffix == "amente" && preceding == "iv" && ends_with(word, "at")
+                && start_of(word, "at") >= p2
+            {
+                replace(word, "at", "");
+            }
+        }
+    }
+    true
+}
+
+/// Removes a verb suffix beginning with `y` in RV, returning `true` if one was removed.
+fn y_verb_suffix(word: &mut Vec<char>, rv: usize) -> bool {
+    let suffixes = &[
+        "ya", "ye", "yan", "yen", "yeron", "yendo", "yo", "yó", "yas", "yes", "yais", "yamos",
+    ];
+    match longest_suffix_from(word, rv, suffixes) {
+        Some(suffix) => {
+            let start = start_of(word, suffix);
+            if start >= 1 && word[start - 1] == 'u' {
+                word.truncate(start);
+                true
+            } else {
+                false
+            }
+        }
+        None => false,
+    }
+}
+
+/// Removes another verb suffix in RV.
+fn verb_suffix(word: &mut Vec<char>, rv: usize) {
+    let suffixes = &[
+        "en", "es", "éis", "emos", "arían", "arías", "arán", "arás", "aríais", "aría", "aréis",
+        "aríamos", "aremos", "ará", "aré", "erían", "erías", "erán", "erás", "eríais", "ería",
+        "eréis", "eríamos", "eremos", "erá", "eré", "irían", "irías", "irán", "irás", "iríais",
+        "iría", "iréis", "iríamos", "iremos", "irá", "iré", "aba", "ada", "ida", "ía", "ara",
+        "iera", "ad", "ed", "id", "ase", "iese", "aste", "iste", "an", "aban", "ían", "aran",
+        "ieran", "asen", "iesen", "aron", "ieron", "ado", "ido", "ando", "iendo", "ió", "ar", "er",
+        "ir", "as", "abas", "adas", "idas", "ías", "aras", "ieras", "ases", "ieses", "ís", "áis",
+        "abais", "íais", "arais", "ierais", "aseis", "ieseis", "asteis", "isteis", "ados",
+        "idos", "amos", "ábamos", "íamos", "imos", "áramos", "iéramos", "iésemos", "ásemos",
+    ];
+    if let Some(suffix) = longest_suffix_from(word, rv, suffixes) {
+        word.truncate(start_of(word, suffix));
+        if ["en", "es", "éis", "emos"].contains(&suffix) && ends_with(word, "gu") {
+            word.pop();
+        }
+    }
+}
+
+/// Removes a residual suffix.
+fn residual_suffix(word: &mut Vec<char>, rv: usize) {
+    let suffixes = &["os", "a", "o", "á", "í", "ó", "e", "é"];
+    if let Some(suffix) = longest_suffix(word, suffixes) {
+        let start = start_of(word, suffix);
+        if start >= rv {
+            word.truncate(start);
+            if (suffix == "e" || suffix == "é") && ends_with(word, "gu")
+                && start_of(word, "u") >= rv
+            {
+                word.pop();
+            }
+        }
+    }
+}
+
+fn remove_accent(c: char) -> char {
+    match c {
+        'á' => 'a',
+        'é' => 'e',
+        'í' => 'i',
+        'ó' => 'o',
+        'ú' => 'u',
+        c => c,
+    }
+}
+
+/// Returns the stem of the given lowercased word.
+pub fn stem(word: &str) -> String {
+    let mut word: Vec<char> = word.chars().collect();
+    let rv = rv(&word);
+    let p1 = region(&word, 0, is_vowel);
+    let p2 = region(&word, p1, is_vowel);
+
+    attached_pronoun(&mut word, rv);
+    if !standard_suffix(&mut word, p1, p2) && !y_verb_suffix(&mut word, rv) {
+        verb_suffix(&mut word, rv);
+    }
+    residual_suffix(&mut word, rv);
+
+    word.into_iter().map(remove_accent).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stem() {
+        let words = [
+            ("cantaba", "cant"),
+            ("chica", "chic"),
+            ("chicas", "chic"),
+            ("comiéndolo", "com"),
+            ("rápidamente", "rapid"),
+            ("nacionales", "nacional"),
+            ("a", "a"),
+        ];
+        for &(word, expected) in words.iter() {
+            assert_eq!(stem(word), expected, "stemming {:?}", word);
+        }
+    }
+}
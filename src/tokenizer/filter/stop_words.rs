@@ -0,0 +1,101 @@
+//! Built-in lists of stop words, i.e., frequent words that carry little meaning.
+//!
+//! The words are lowercased, so the lists are meant to be used after a
+//! [`TokenFilter::LowerCase`][lowercase] filter. Except for English, they are based on the lists
+//! of the Snowball project.
+//!
+//! [lowercase]: ../enum.TokenFilter.html#variant.LowerCase
+use tokenizer::language::Language;
+
+/// Returns the built-in list of stop words of the given language.
+pub fn of(language: Language) -> &'static [&'static str] {
+    match language {
+        Language::Dutch => DUTCH,
+        Language::English => ENGLISH,
+        Language::French => FRENCH,
+        Language::German => GERMAN,
+        Language::Italian => ITALIAN,
+        Language::Portuguese => PORTUGUESE,
+        Language::Russian => RUSSIAN,
+        Language::Spanish => SPANISH,
+    }
+}
+
+const DUTCH: &[&str] = &[
+    "aan", "al", "alles", "als", "altijd", "andere", "ben", "bij", "daar", "dan", "dat", "de",
+    "der", "deze", "die", "dit", "doch", "doen", "door", "dus", "een", "eens", "en", "er", "ge",
+    "geen", "geweest", "haar", "had", "heb", "hebben", "heeft", "hem", "het", "hier", "hij", "hoe",
+    "hun", "iemand", "iets", "ik", "in", "is", "ja", "je", "kan", "kon", "kunnen", "maar", "me",
+    "meer", "men", "met", "mij", "mijn", "moet", "na", "naar", "niet", "niets", "nog", "nu", "of",
+    "om", "omdat", "onder", "ons", "ook", "op", "over", "reeds", "te", "tegen", "toch", "toen",
+    "tot", "u", "uit", "uw", "van", "veel", "voor", "want", "waren", "was", "wat", "werd", "wezen",
+    "wie", "wil", "worden", "wordt", "zal", "ze", "zelf", "zich", "zij", "zijn", "zo", "zonder",
+    "zou",
+];
+
+const ENGLISH: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it",
+    "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there", "these",
+    "they", "this", "to", "was", "will", "with",
+];
+
+const FRENCH: &[&str] = &[
+    "au", "aux", "avec", "ce", "ces", "dans", "de", "des", "du", "elle", "en", "et", "eux", "il",
+    "je", "la", "le", "les", "leur", "lui", "ma", "mais", "me", "même", "mes", "moi", "mon", "ne",
+    "nos", "notre", "nous", "on", "ou", "par", "pas", "pour", "qu", "que", "qui", "sa", "se",
+    "ses", "son", "sur", "ta", "te", "tes", "toi", "ton", "tu", "un", "une", "vos", "votre",
+    "vous", "c", "d", "j", "l", "à", "m", "n", "s", "t", "y", "été", "étée", "étées", "étés",
+    "étant", "suis", "es", "est", "sommes", "êtes", "sont", "serai", "sera", "ai", "as", "avons",
+    "avez", "ont", "aurai", "aura", "avait", "eu",
+];
+
+const GERMAN: &[&str] = &[
+    "aber", "alle", "allem", "allen", "aller", "als", "also", "am", "an", "ander", "andere", "auch",
+    "auf", "aus", "bei", "bin", "bis", "bist", "da", "damit", "dann", "das", "dass", "dein",
+    "dem", "den", "der", "des", "dich", "die", "dies", "diese", "dir", "doch", "dort", "du",
+    "durch", "ein", "eine", "einem", "einen", "einer", "eines", "er", "es", "etwas", "für",
+    "hab", "habe", "haben", "hat", "hatte", "ich", "ihm", "ihn", "ihr", "ihre", "im", "in", "ist",
+    "jede", "jetzt", "kann", "kein", "keine", "man", "mein", "mich", "mir", "mit", "muss", "nach",
+    "nicht", "nichts", "noch", "nun", "nur", "ob", "oder", "ohne", "sehr", "sein", "sich", "sie",
+    "sind", "so", "um", "und", "uns", "unter", "viel", "vom", "von", "vor", "war", "waren",
+    "warst", "was", "weil", "wenn", "wer", "wie", "wir", "wird", "wo", "zu", "zum", "zur", "über",
+];
+
+const ITALIAN: &[&str] = &[
+    "a", "ad", "al", "alla", "alle", "agli", "ai", "anche", "che", "chi", "ci", "come", "con",
+    "da", "dal", "dalla", "degli", "dei", "del", "della", "delle", "dello", "di", "e", "è", "ed",
+    "era", "gli", "ha", "hanno", "ho", "i", "il", "in", "io", "la", "le", "lei", "lo", "loro",
+    "lui", "ma", "mi", "mio", "ne", "negli", "nei", "nel", "nella", "noi", "non", "o", "per",
+    "più", "quale", "quella", "quello", "questa", "questo", "se", "si", "sia", "sono", "su",
+    "sua", "sul", "sulla", "suo", "ti", "tra", "tu", "un", "una", "uno", "voi",
+];
+
+const PORTUGUESE: &[&str] = &[
+    "a", "ao", "aos", "as", "até", "com", "como", "da", "das", "de", "dele", "deles", "do", "dos",
+    "e", "é", "ela", "elas", "ele", "eles", "em", "entre", "era", "essa", "esse", "esta", "este",
+    "eu", "foi", "há", "isso", "isto", "já", "lhe", "mais", "mas", "me", "mesmo", "meu", "muito",
+    "na", "nas", "não", "nem", "no", "nos", "nós", "num", "numa", "o", "os", "ou", "para", "pela",
+    "pelo", "por", "quando", "que", "quem", "se", "seu", "sua", "são", "também", "te", "tem",
+    "um", "uma", "você",
+];
+
+const RUSSIAN: &[&str] = &[
+    "а", "без", "более", "бы", "был", "была", "были", "было", "быть", "в", "вам", "вас", "весь",
+    "во", "вот", "все", "всего", "вы", "где", "да", "даже", "для", "до", "его", "ее", "если",
+    "есть", "еще", "же", "за", "здесь", "и", "из", "или", "им", "их", "к", "как", "ко", "когда",
+    "кто", "ли", "либо", "мне", "может", "мы", "на", "над", "надо", "наш", "не", "него", "нее",
+    "нет", "ни", "них", "но", "ну", "о", "об", "однако", "он", "она", "они", "оно", "от", "очень",
+    "по", "под", "при", "с", "со", "так", "также", "такой", "там", "те", "тем", "то", "того",
+    "тоже", "той", "только", "том", "ты", "у", "уже", "хотя", "чего", "чей", "чем", "что",
+    "чтобы", "чье", "чья", "эта", "эти", "это", "я",
+];
+
+const SPANISH: &[&str] = &[
+    "a", "al", "algo", "ante", "como", "con", "contra", "cual", "cuando", "de", "del", "desde",
+    "donde", "durante", "e", "el", "él", "ella", "ellas", "ellos", "en", "entre", "era", "es",
+    "esa", "ese", "eso", "esta", "está", "este", "esto", "fue", "ha", "hay", "la", "las", "le",
+    "les", "lo", "los", "más", "me", "mi", "mis", "muy", "nada", "ni", "no", "nos", "o", "os",
+    "otra", "otro", "para", "pero", "poco", "por", "porque", "que", "qué", "quien", "se", "ser",
+    "si", "sí", "sin", "sobre", "son", "su", "sus", "también", "te", "tiene", "todo", "tu", "un",
+    "una", "uno", "unos", "y", "ya", "yo",
+];
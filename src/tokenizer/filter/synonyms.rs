@@ -0,0 +1,471 @@
+//! Synonyms of words and phrases, in the format of Solr synonym files.
+//!
+//! Each line of a synonym file is a rule, either:
+//! - a list of equivalent phrases separated by commas, e.g., `tv, television`, where each phrase is
+//!   replaced by all the phrases of the list;
+//! - an explicit mapping, e.g., `usa, us => united states of america`, where the phrases on the
+//!   left are replaced by the phrases on the right.
+//!
+//! Empty lines and lines starting with `#` are ignored, and a comma within a phrase is escaped
+//! with a backslash. The words of a phrase are separated by whitespace and matched as is against
+//! the tokens, so the rules are expected to be lowercased if the tokens are.
+//!
+//! The first word of a synonym is at the position of the first word of the replaced phrase, and
+//! the following words at the following positions, so that a [`PhraseQuery`][phrase] matches
+//! either phrase. The synonyms of a single word are stacked at its position, and the following
+//! tokens are moved after the longest synonym. The last word of a shorter synonym spans the
+//! remaining positions, see [`Token::position_length`][length]; as phrase queries only look at the
+//! positions, a phrase continuing after a shorter synonym needs a slop covering these positions.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::tokenizer::Tokenizer;
+//! use ::iryfful::tokenizer::filter::TokenFilter;
+//! use ::iryfful::tokenizer::filter::synonyms::SynonymMap;
+//! use ::iryfful::tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+//!
+//! let synonyms = SynonymMap::parse("
+//!     # television
+//!     tv, television
+//!     usa => united states
+//! ").unwrap();
+//!
+//! let mut tokenizer = WhiteSpaceTokenizer::new();
+//! tokenizer.add_filter(TokenFilter::Synonyms(synonyms));
+//!
+//! let tokens: Vec<(u32, String)> = tokenizer
+//!     .tokenize("usa tv show")
+//!     .map(|token| (token.position, token.token))
+//!     .collect();
+//!
+//! assert_eq!(tokens, vec![
+//!     (1, String::from("united")),
+//!     (2, String::from("states")),
+//!     (3, String::from("tv")),
+//!     (3, String::from("television")),
+//!     (4, String::from("show")),
+//! ]);
+//! ```
+//!
+//! [length]: ../../struct.Token.html#structfield.position_length
+//! [phrase]: ../../../search/query/phrase_query/index.html
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::Path;
+use tokenizer::Token;
+use tokenizer::error::TokenizerError;
+
+/// A map of phrases to their synonyms.
+#[derive(Debug, Default)]
+pub struct SynonymMap {
+    synonyms: HashMap<Vec<String>, Vec<Vec<String>>>,
+    /// The number of words of the longest replaced phrase
+    max_words: usize,
+}
+
+impl SynonymMap {
+    /// Parses the given rules in the Solr format.
+    ///
+    /// # Errors
+    ///
+    /// A [`TokenizerError::InvalidSynonymRule`] error is returned if a line is not a valid rule.
+    pub fn parse(rules: &str) -> Result<SynonymMap, TokenizerError> {
+        let mut map: SynonymMap = Default::default();
+        for (i, line) in rules.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = |reason: &str| TokenizerError::InvalidSynonymRule {
+                line: i + 1,
+                reason: reason.to_string(),
+            };
+            let sides: Vec<Vec<String>> = line.split("=>").map(split_phrases).collect();
+            if sides.iter().any(|phrases| phrases.iter().any(String::is_empty)) {
+                return Err(invalid("empty phrase"));
+            }
+            let phrases = |side: &[String]| -> Vec<Vec<String>> {
+                side.iter()
+                    .map(|phrase| phrase.split_whitespace().map(String::from).collect())
+                    .collect()
+            };
+            match sides.len() {
+                1 => map.add_equivalent(phrases(&sides[0])),
+                2 => map.add_mapping(phrases(&sides[0]), &phrases(&sides[1])),
+                _ => return Err(invalid("more than one mapping")),
+            }
+        }
+        Ok(map)
+    }
+
+    /// Parses the rules of the given file, see [`SynonymMap::parse`].
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of [`SynonymMap::parse`], a
+    /// [`TokenizerError::UnreadableFile`] error is returned if the file cannot be read.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<SynonymMap, TokenizerError> {
+        let path = path.as_ref();
+        let rules = fs::read_to_string(path).map_err(|err| TokenizerError::UnreadableFile {
+            path: path.display().to_string(),
+            reason: err.to_string(),
+        })?;
+        SynonymMap::parse(&rules)
+    }
+
+    /// Adds phrases equivalent to each other, given as their list of words.
+    pub fn add_equivalent(&mut self, phrases: Vec<Vec<String>>) {
+        for phrase in phrases.iter() {
+            self.add(phrase.clone(), &phrases);
+        }
+    }
+
+    /// Adds phrases replaced by others, given as their list of words.
+    pub fn add_mapping(&mut self, from: Vec<Vec<String>>, to: &[Vec<String>]) {
+        for phrase in from {
+            self.add(phrase, to);
+        }
+    }
+
+    fn add(&mut self, phrase: Vec<String>, synonyms: &[Vec<String>]) {
+        if phrase.is_empty() {
+            return;
+        }
+        self.max_words = self.max_words.max(phrase.len());
+        let entry = self.synonyms.entry(phrase).or_default();
+        for synonym in synonyms.iter().filter(|synonym| !synonym.is_empty()) {
+            if !entry.contains(synonym) {
+                entry.push(synonym.clone());
+            }
+        }
+    }
+
+    /// Returns `true` if there are no synonyms.
+    pub fn is_empty(&self) -> bool {
+        self.synonyms.is_empty()
+    }
+
+    /// Returns the given stream of [`Token`]s with the phrases replaced by their synonyms.
+    ///
+    /// The longest phrase with synonyms starting at a token is replaced, and the tokens are
+    /// returned by increasing position.
+    pub fn filter<'a>(
+        &'a self,
+        tokens: Box<Iterator<Item = Token> + 'a>,
+    ) -> Box<Iterator<Item = Token> + 'a> {
+        Box::new(SynonymStream {
+            tokens,
+            synonyms: self,
+            input: VecDeque::new(),
+            output: VecDeque::new(),
+            shift: 0,
+        })
+    }
+}
+
+/// Splits a list of phrases on the commas that are not escaped.
+fn split_phrases(list: &str) -> Vec<String> {
+    let mut phrases = vec![String::new()];
+    let mut chars = list.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => if let Some(escaped) = chars.next() {
+                phrases.last_mut().unwrap().push(escaped);
+            },
+            ',' => phrases.push(String::new()),
+            c => phrases.last_mut().unwrap().push(c),
+        }
+    }
+    phrases
+        .into_iter()
+        .map(|phrase| phrase.trim().to_string())
+        .collect()
+}
+
+struct SynonymStream<'a> {
+    tokens: Box<Iterator<Item = Token> + 'a>,
+    synonyms: &'a SynonymMap,
+    /// The tokens read ahead to match phrases
+    input: VecDeque<Token>,
+    /// The tokens to return, sorted by position
+    output: VecDeque<Token>,
+    /// The number of positions added by the synonyms longer than their phrase so far
+    shift: u32,
+}
+
+impl<'a> SynonymStream<'a> {
+    /// Returns the number of tokens of the longest phrase with synonyms at the start of the
+    /// input, along with the synonyms.
+    fn longest_match(&self) -> Option<(usize, &'a [Vec<String>])> {
+        let first = self.input[0].position;
+        let mut words = Vec::with_capacity(self.input.len());
+        let mut longest = None;
+        for (i, token) in self.input.iter().enumerate() {
+            // the words of a phrase are at consecutive positions
+            if token.position != first + i as u32 {
+                break;
+            }
+            words.push(token.token.clone());
+            if let Some(synonyms) = self.synonyms.synonyms.get(&words) {
+                longest = Some((i + 1, synonyms.as_slice()));
+            }
+        }
+        longest
+    }
+
+    fn push_output(&mut self, token: Token) {
+        let i = self.output
+            .iter()
+            .position(|output| output.position > token.position)
+            .unwrap_or(self.output.len());
+        self.output.insert(i, token);
+    }
+}
+
+impl<'a> Iterator for SynonymStream<'a> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        loop {
+            while self.input.len() < self.synonyms.max_words.max(1) {
+                match self.tokens.next() {
+                    Some(token) => self.input.push_back(token),
+                    None => break,
+                }
+            }
+            let ready = match (self.output.front(), self.input.front()) {
+                (Some(output), Some(input)) => output.position <= input.position + self.shift,
+                (Some(_), None) => true,
+                (None, None) => return None,
+                (None, Some(_)) => false,
+            };
+            if ready {
+                return self.output.pop_front();
+            }
+
+            match self.longest_match() {
+                None => {
+                    let mut token = self.input.pop_front().unwrap();
+                    token.position += self.shift;
+                    self.push_output(token);
+                }
+                Some((n, synonyms)) => {
+                    let phrase: Vec<Token> = self.input.drain(..n).collect();
+                    let position = phrase[0].position + self.shift;
+                    let start_offset = phrase[0].start_offset;
+                    let end_offset = phrase[n - 1].end_offset;
+                    let length = synonyms.iter().map(Vec::len).max().unwrap_or(0).max(n);
+                    // the following tokens are moved after the longest synonym
+                    self.shift += (length - n) as u32;
+                    for synonym in synonyms {
+                        for (i, word) in synonym.iter().enumerate() {
+                            // the last word of a shorter synonym spans the remaining positions
+                            let position_length = if i + 1 == synonym.len() {
+                                (length - i) as u32
+                            } else {
+                                1
+                            };
+                            self.push_output(Token {
+                                token: word.clone(),
+                                position: position + i as u32,
+                                position_length,
+                                start_offset,
+                                end_offset,
+                                keyword: false,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use index::Index;
+    use index::document::Document;
+    use search::IndexSearcher;
+    use search::SearchHit;
+    use search::query::Query;
+    use search::query::phrase_query::PhraseQuery;
+    use search::query::term_query::TermQuery;
+    use tokenizer::Tokenizer;
+    use tokenizer::filter::TokenFilter;
+    use tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+
+    fn tokens(synonyms: SynonymMap, input: &str) -> Vec<(u32, String, u32, u32)> {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::Synonyms(synonyms));
+        tokenizer
+            .tokenize(input)
+            .map(|token| {
+                (
+                    token.position,
+                    token.token,
+                    token.start_offset,
+                    token.end_offset,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let map = SynonymMap::parse(
+            "
+            # comment
+            tv, television
+            i-pod, i pod => ipod
+            a\\,b => c
+            ",
+        ).unwrap();
+        let words = |phrase: &str| -> Vec<String> {
+            phrase.split_whitespace().map(String::from).collect()
+        };
+
+        assert_eq!(map.max_words, 2);
+        assert_eq!(
+            map.synonyms[&words("tv")],
+            vec![words("tv"), words("television")]
+        );
+        assert_eq!(
+            map.synonyms[&words("television")],
+            vec![words("tv"), words("television")]
+        );
+        assert_eq!(map.synonyms[&words("i pod")], vec![words("ipod")]);
+        assert_eq!(map.synonyms[&words("a,b")], vec![words("c")]);
+        assert!(!map.synonyms.contains_key(&words("ipod")));
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        match SynonymMap::parse("a => b\n\na => b => c") {
+            Err(TokenizerError::InvalidSynonymRule { line, .. }) => assert_eq!(line, 3),
+            _ => panic!("the rule should be invalid"),
+        }
+        match SynonymMap::parse("a, , b") {
+            Err(TokenizerError::InvalidSynonymRule { line, .. }) => assert_eq!(line, 1),
+            _ => panic!("the rule should be invalid"),
+        }
+        match SynonymMap::load("/nonexistent/synonyms.txt") {
+            Err(TokenizerError::UnreadableFile { path, .. }) => {
+                assert_eq!(path, "/nonexistent/synonyms.txt")
+            }
+            _ => panic!("the file should be unreadable"),
+        }
+    }
+
+    #[test]
+    fn test_multi_word_synonyms() {
+        let map = SynonymMap::parse("united states, usa\nny => new york").unwrap();
+
+        assert_eq!(
+            tokens(map, "ny to united states today"),
+            vec![
+                (1, String::from("new"), 0, 2),
+                (2, String::from("york"), 0, 2),
+                (3, String::from("to"), 3, 5),
+                (4, String::from("united"), 6, 19),
+                (4, String::from("usa"), 6, 19),
+                (5, String::from("states"), 6, 19),
+                (6, String::from("today"), 20, 25),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_longest_match() {
+        let map = SynonymMap::parse("new => novel\nnew york => ny").unwrap();
+
+        assert_eq!(
+            tokens(map, "new york new"),
+            vec![
+                (1, String::from("ny"), 0, 8),
+                (3, String::from("novel"), 9, 12),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_position_length() {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::Synonyms(
+            SynonymMap::parse("new york city => nyc, big apple").unwrap(),
+        ));
+
+        let tokens: Vec<(u32, u32, String)> = tokenizer
+            .tokenize("new york city hall")
+            .map(|token| (token.position, token.position_length, token.token))
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                (1, 3, String::from("nyc")),
+                (1, 1, String::from("big")),
+                (2, 2, String::from("apple")),
+                (4, 1, String::from("hall")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_synonyms() {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::Synonyms(
+            SynonymMap::parse("tv, television\nusa => united states of america").unwrap(),
+        ));
+        let mut index: Index = Default::default();
+        index.set_mapping(String::from("title"), tokenizer).unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("title", "tv show");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("title", "usa today");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = &IndexSearcher::new(&index);
+
+        let pq = PhraseQuery::new("title", vec!["television", "show"]);
+        let mut iter = pq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), None);
+
+        let pq = PhraseQuery::new("title", vec!["states", "of", "america"]);
+        let mut iter = pq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(1)));
+        assert_eq!(iter.next(), None);
+
+        let tq = TermQuery::new("title", "usa");
+        let mut iter = tq.execute(index_search);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_phrases_after_longer_synonyms() {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::Synonyms(
+            SynonymMap::parse("tv, television\nusa => united states").unwrap(),
+        ));
+        let mut index: Index = Default::default();
+        index.set_mapping(String::from("title"), tokenizer).unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("title", "usa tv show");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = &IndexSearcher::new(&index);
+        let hits = |terms: Vec<&str>| -> Vec<SearchHit> {
+            PhraseQuery::new("title", terms).execute(index_search).collect()
+        };
+
+        assert_eq!(hits(vec!["united", "states", "tv"]), vec![SearchHit::new(0)]);
+        assert_eq!(hits(vec!["states", "television"]), vec![SearchHit::new(0)]);
+        assert_eq!(hits(vec!["united", "tv"]), vec![]);
+    }
+}
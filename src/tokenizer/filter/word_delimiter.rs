@@ -0,0 +1,460 @@
+//! Splitting of words into their parts, e.g., `getHTTPResponse` into `get`, `HTTP` and `Response`,
+//! `wi-fi` into `wi` and `fi`, or `SD500` into `SD` and `500`.
+//!
+//! A word is split on the characters that are neither letters nor digits, and optionally on case
+//! changes and on transitions between letters and digits. The parts of a word are at consecutive
+//! positions, and the following tokens are moved accordingly, so that a
+//! [`PhraseQuery`][phrase] matches the parts of a word.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::tokenizer::Tokenizer;
+//! use ::iryfful::tokenizer::filter::TokenFilter;
+//! use ::iryfful::tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+//!
+//! let mut tokenizer = WhiteSpaceTokenizer::new();
+//! tokenizer.add_filter(TokenFilter::WordDelimiter(Default::default()));
+//!
+//! let tokens: Vec<(u32, String)> = tokenizer
+//!     .tokenize("getHTTPResponse wi-fi")
+//!     .map(|token| (token.position, token.token))
+//!     .collect();
+//!
+//! assert_eq!(tokens, vec![
+//!     (1, String::from("get")),
+//!     (2, String::from("HTTP")),
+//!     (3, String::from("Response")),
+//!     (4, String::from("wi")),
+//!     (5, String::from("fi")),
+//! ]);
+//! ```
+//!
+//! [phrase]: ../../../search/query/phrase_query/index.html
+use std::collections::VecDeque;
+use tokenizer::Token;
+
+/// The configuration of a [`TokenFilter::WordDelimiter`][word_delimiter] filter.
+///
+/// By default, words are split on case changes and on transitions between letters and digits,
+/// and only the parts are outputted.
+///
+/// [word_delimiter]: ../enum.TokenFilter.html#variant.WordDelimiter
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WordDelimiter {
+    /// Whether to split on case changes, e.g., `camelCase` into `camel` and `Case`
+    pub split_on_case_change: bool,
+    /// Whether to split on transitions between letters and digits, e.g., `SD500` into `SD` and
+    /// `500`
+    pub split_on_numerics: bool,
+    /// Whether to also output the adjacent parts made of letters joined, e.g., `wifi` for `wi-fi`
+    pub catenate_words: bool,
+    /// Whether to also output the adjacent parts made of digits joined, e.g., `500100` for
+    /// `500-100`
+    pub catenate_numbers: bool,
+    /// Whether to also output all the parts joined, e.g., `SD500` for `SD-500`
+    pub catenate_all: bool,
+    /// Whether to also output the original word, spanning the positions of its parts
+    pub preserve_original: bool,
+}
+
+impl Default for WordDelimiter {
+    fn default() -> WordDelimiter {
+        WordDelimiter {
+            split_on_case_change: true,
+            split_on_numerics: true,
+            catenate_words: false,
+            catenate_numbers: false,
+            catenate_all: false,
+            preserve_original: false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CharClass {
+    Lower,
+    Upper,
+    Digit,
+    Delimiter,
+}
+
+fn class_of(c: char) -> CharClass {
+    if c.is_uppercase() {
+        CharClass::Upper
+    } else if c.is_numeric() {
+        CharClass::Digit
+    } else if c.is_alphabetic() {
+        // letters without case are handled as lowercase letters
+        CharClass::Lower
+    } else {
+        CharClass::Delimiter
+    }
+}
+
+impl WordDelimiter {
+    /// Returns `true` if a word is split between characters of the given classes, knowing the
+    /// class of the next character.
+    fn is_boundary(&self, prev: CharClass, class: CharClass, next: Option<CharClass>) -> bool {
+        match (prev, class) {
+            (CharClass::Digit, CharClass::Lower)
+            | (CharClass::Digit, CharClass::Upper)
+            | (CharClass::Lower, CharClass::Digit)
+            | (CharClass::Upper, CharClass::Digit) => self.split_on_numerics,
+            (CharClass::Lower, CharClass::Upper) => self.split_on_case_change,
+            // the last letter of an acronym followed by a capitalized word, e.g., `HTTPResponse`
+            (CharClass::Upper, CharClass::Upper) => {
+                self.split_on_case_change && next == Some(CharClass::Lower)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the byte ranges of the parts of the given word.
+    pub fn split(&self, word: &str) -> Vec<(usize, usize)> {
+        let chars: Vec<(usize, CharClass)> = word
+            .char_indices()
+            .map(|(offset, c)| (offset, class_of(c)))
+            .collect();
+        let mut parts = Vec::new();
+        let mut start = None;
+        for (i, &(offset, class)) in chars.iter().enumerate() {
+            if class == CharClass::Delimiter {
+                if let Some(start) = start.take() {
+                    parts.push((start, offset));
+                }
+                continue;
+            }
+            match start {
+                Some(part_start) => {
+                    let next = chars.get(i + 1).map(|&(_, class)| class);
+                    if self.is_boundary(chars[i - 1].1, class, next) {
+                        parts.push((part_start, offset));
+                        start = Some(offset);
+                    }
+                }
+                None => start = Some(offset),
+            }
+        }
+        if let Some(start) = start {
+            parts.push((start, word.len()));
+        }
+        parts
+    }
+
+    /// Returns the given stream of [`Token`]s with the words split into their parts.
+    ///
+    /// Keyword tokens are left untouched, and tokens without any letter or digit are removed,
+    /// unless the original words are preserved.
+    pub fn filter<'a>(
+        &'a self,
+        tokens: Box<Iterator<Item = Token> + 'a>,
+    ) -> Box<Iterator<Item = Token> + 'a> {
+        Box::new(WordDelimiterStream {
+            tokens,
+            delimiter: self,
+            position: 0,
+            shift: 0,
+            extra: 0,
+            next: None,
+            exhausted: false,
+            output: VecDeque::new(),
+        })
+    }
+}
+
+struct WordDelimiterStream<'a> {
+    tokens: Box<Iterator<Item = Token> + 'a>,
+    delimiter: &'a WordDelimiter,
+    /// The original position of the last token read
+    position: u32,
+    /// The number of positions added by the parts of the words before that position
+    shift: u32,
+    /// The number of positions added by the parts of the words at that position
+    extra: u32,
+    /// The new position of the token read ahead, along with the tokens created from it
+    next: Option<(u32, Vec<Token>)>,
+    exhausted: bool,
+    /// The tokens to return, sorted by position
+    output: VecDeque<Token>,
+}
+
+impl<'a> WordDelimiterStream<'a> {
+    /// Returns the new position of the given token, along with the tokens created from it.
+    fn delimit(&mut self, mut token: Token) -> (u32, Vec<Token>) {
+        if token.position != self.position {
+            self.shift += self.extra;
+            self.extra = 0;
+            self.position = token.position;
+        }
+        let base = token.position + self.shift;
+        token.position = base;
+        if token.keyword {
+            return (base, vec![token]);
+        }
+
+        let config = self.delimiter;
+        let parts = config.split(&token.token);
+        if parts.is_empty() {
+            return (base, if config.preserve_original { vec![token] } else { vec![] });
+        }
+        if parts.len() == 1 && parts[0] == (0, token.token.len()) {
+            return (base, vec![token]);
+        }
+        self.extra = self.extra.max(parts.len() as u32 - 1);
+
+        // the offsets of the parts are only known if the text of the token is the text it spans,
+        // i.e., unless a previous filter changed it, e.g., a stemmer
+        let has_offsets = token.token.len() as u32 == token.end_offset - token.start_offset;
+        let part_token = |start: usize, end: usize, text: String, position: u32, length: usize| {
+            let (start_offset, end_offset) = if has_offsets {
+                (
+                    token.start_offset + start as u32,
+                    token.start_offset + end as u32,
+                )
+            } else {
+                (token.start_offset, token.end_offset)
+            };
+            Token {
+                token: text,
+                position,
+                position_length: length as u32,
+                start_offset,
+                end_offset,
+                keyword: false,
+            }
+        };
+        let join = |parts: &[(usize, usize)]| -> String {
+            parts
+                .iter()
+                .map(|&(start, end)| &token.token[start..end])
+                .collect()
+        };
+
+        let mut tokens = Vec::with_capacity(parts.len() + 1);
+        for (i, &(start, end)) in parts.iter().enumerate() {
+            let text = token.token[start..end].to_string();
+            tokens.push(part_token(start, end, text, base + i as u32, 1));
+        }
+
+        // the runs of adjacent parts made of letters or of digits
+        let mut run_start = 0;
+        for i in 1..=parts.len() {
+            let is_numeric = |part: &(usize, usize)| {
+                token.token[part.0..].starts_with(|c: char| c.is_numeric())
+            };
+            if i < parts.len() && is_numeric(&parts[i]) == is_numeric(&parts[run_start]) {
+                continue;
+            }
+            let run = &parts[run_start..i];
+            let catenate = if is_numeric(&run[0]) {
+                config.catenate_numbers
+            } else {
+                config.catenate_words
+            };
+            if catenate && run.len() > 1 {
+                let (start, end) = (run[0].0, run[run.len() - 1].1);
+                let position = base + run_start as u32;
+                tokens.push(part_token(start, end, join(run), position, run.len()));
+            }
+            run_start = i;
+        }
+        if config.catenate_all {
+            let text = join(&parts);
+            if !tokens.iter().any(|token| token.position == base && token.token == text) {
+                let end = parts[parts.len() - 1].1;
+                tokens.push(part_token(parts[0].0, end, text, base, parts.len()));
+            }
+        }
+
+        if config.preserve_original {
+            token.position_length = parts.len() as u32;
+            tokens.insert(0, token);
+        }
+        // the catenated parts follow the first part they are made of
+        tokens.sort_by_key(|token| token.position);
+        (base, tokens)
+    }
+
+    fn push_output(&mut self, token: Token) {
+        let i = self.output
+            .iter()
+            .position(|output| output.position > token.position)
+            .unwrap_or(self.output.len());
+        self.output.insert(i, token);
+    }
+}
+
+impl<'a> Iterator for WordDelimiterStream<'a> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        loop {
+            if self.next.is_none() && !self.exhausted {
+                match self.tokens.next() {
+                    Some(token) => self.next = Some(self.delimit(token)),
+                    None => self.exhausted = true,
+                }
+            }
+            // the tokens before the token read ahead are final
+            match self.next {
+                Some((base, _)) => match self.output.front() {
+                    Some(token) if token.position < base => return self.output.pop_front(),
+                    _ => {}
+                },
+                None => return self.output.pop_front(),
+            }
+            if let Some((_, tokens)) = self.next.take() {
+                for token in tokens {
+                    self.push_output(token);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use index::Index;
+    use index::document::Document;
+    use search::IndexSearcher;
+    use search::SearchHit;
+    use search::highlight::Highlighter;
+    use search::query::Query;
+    use search::query::phrase_query::PhraseQuery;
+    use search::query::term_query::TermQuery;
+    use tokenizer::Tokenizer;
+    use tokenizer::char_filter::CharFilter;
+    use tokenizer::filter::TokenFilter;
+    use tokenizer::whitespace_tokenizer::WhiteSpaceTokenizer;
+
+    fn tokens(delimiter: WordDelimiter, input: &str) -> Vec<(u32, u32, String, u32, u32)> {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::WordDelimiter(delimiter));
+        tokenizer
+            .tokenize(input)
+            .map(|token| {
+                (
+                    token.position,
+                    token.position_length,
+                    token.token,
+                    token.start_offset,
+                    token.end_offset,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_split() {
+        let delimiter: WordDelimiter = Default::default();
+        assert_eq!(delimiter.split("getHTTPResponse"), vec![(0, 3), (3, 7), (7, 15)]);
+        assert_eq!(delimiter.split("SD500"), vec![(0, 2), (2, 5)]);
+        assert_eq!(delimiter.split("--wi-fi--"), vec![(2, 4), (5, 7)]);
+        assert_eq!(delimiter.split("Ünïcode2"), vec![(0, 9), (9, 10)]);
+        assert!(delimiter.split("--").is_empty());
+
+        let delimiter = WordDelimiter {
+            split_on_case_change: false,
+            split_on_numerics: false,
+            ..Default::default()
+        };
+        assert_eq!(delimiter.split("getHTTPResponse"), vec![(0, 15)]);
+        assert_eq!(delimiter.split("SD500"), vec![(0, 5)]);
+    }
+
+    #[test]
+    fn test_catenate() {
+        let delimiter = WordDelimiter {
+            catenate_words: true,
+            catenate_numbers: true,
+            catenate_all: true,
+            preserve_original: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            tokens(delimiter, "wi-fi 500-42x a"),
+            vec![
+                (1, 2, String::from("wi-fi"), 0, 5),
+                (1, 1, String::from("wi"), 0, 2),
+                (1, 2, String::from("wifi"), 0, 5),
+                (2, 1, String::from("fi"), 3, 5),
+                (3, 3, String::from("500-42x"), 6, 13),
+                (3, 1, String::from("500"), 6, 9),
+                (3, 2, String::from("50042"), 6, 12),
+                (3, 3, String::from("50042x"), 6, 13),
+                (4, 1, String::from("42"), 10, 12),
+                (5, 1, String::from("x"), 12, 13),
+                (6, 1, String::from("a"), 14, 15),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_phrase() {
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_filter(TokenFilter::WordDelimiter(Default::default()));
+        tokenizer.add_filter(TokenFilter::LowerCase);
+        let mut index: Index = Default::default();
+        index.set_mapping(String::from("field1"), tokenizer).unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("field1", "call getHTTPResponse now");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = &IndexSearcher::new(&index);
+
+        let pq = PhraseQuery::new("field1", vec!["http", "response"]);
+        let mut iter = pq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), None);
+
+        // the following tokens are moved after the parts
+        let pq = PhraseQuery::new("field1", vec!["response", "now"]);
+        let mut iter = pq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), None);
+
+        let pq = PhraseQuery::new("field1", vec!["call", "response"]);
+        let mut iter = pq.execute(index_search);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_char_filtered_offsets() {
+        // the mapped text has the same length as the original, but not the same characters, and
+        // the parts are split in the mapped text
+        let mut tokenizer = WhiteSpaceTokenizer::new();
+        tokenizer.add_char_filter(CharFilter::mapping(&[("é", "e"), ("&", "and")]));
+        tokenizer.add_filter(TokenFilter::WordDelimiter(Default::default()));
+        let tokens: Vec<(u32, String, u32, u32)> = tokenizer
+            .tokenize("Yéaé/&wi")
+            .map(|token| {
+                (
+                    token.position,
+                    token.token,
+                    token.start_offset,
+                    token.end_offset,
+                )
+            })
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                (1, String::from("Yeae"), 0, 6),
+                (2, String::from("andwi"), 7, 10),
+            ]
+        );
+
+        let mut index: Index = Default::default();
+        index.set_mapping(String::from("field1"), tokenizer).unwrap();
+        let mut highlighter = Highlighter::new(&index);
+        highlighter.set_tags("[", "]");
+        let tq = TermQuery::new("field1", "andwi");
+        let fragments = highlighter.highlight(&tq, "field1", "Yéaé/&wi").unwrap();
+        assert_eq!(fragments[0].text, "Yéaé/[&wi]");
+    }
+}
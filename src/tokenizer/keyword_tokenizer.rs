@@ -0,0 +1,124 @@
+//! A [`Tokenizer`] that outputs the whole string as a single split.
+//!
+//! It is meant for exact-value fields such as identifiers, tags or status codes. The configured
+//! [`TokenFilter`]s are still applied to the value, e.g., for lowercasing it.
+//!
+//! This tokenizer is a normalizer: the term of a [`TermQuery`][term] looked up against a field
+//! mapped with it goes through the same filters, so that the lookup matches the indexed value.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::index::document::Document;
+//! use ::iryfful::index::Index;
+//! use ::iryfful::search::IndexSearcher;
+//! use ::iryfful::search::SearchHit;
+//! use ::iryfful::search::query::Query;
+//! use ::iryfful::search::query::term_query::TermQuery;
+//! use ::iryfful::tokenizer::Tokenizer;
+//! use ::iryfful::tokenizer::filter::TokenFilter;
+//! use ::iryfful::tokenizer::keyword_tokenizer::KeywordTokenizer;
+//!
+//! let mut tokenizer = KeywordTokenizer::new();
+//! tokenizer.add_filter(TokenFilter::LowerCase);
+//!
+//! let mut index: Index = Default::default();
+//! index.set_mapping(String::from("status"), tokenizer).unwrap();
+//!
+//! let mut doc: Document = Default::default();
+//! doc.add_field("status", "In Progress");
+//! index.add_doc(&doc).unwrap();
+//!
+//! let index_search = &IndexSearcher::new(&index);
+//! let tq = TermQuery::new("status", "IN PROGRESS");
+//! let mut iter = tq.execute(index_search);
+//!
+//! assert_eq!(iter.next(), Some(SearchHit::new(0)));
+//! assert_eq!(iter.next(), None);
+//! ```
+//!
+//! [term]: ../../search/query/term_query/index.html
+use super::Tokenizer;
+use super::char_filter::CharFilter;
+use super::filter::TokenFilter;
+use std::iter;
+
+pub struct KeywordTokenizer {
+    filters: Vec<TokenFilter>,
+    char_filters: Vec<CharFilter>,
+}
+
+impl Tokenizer for KeywordTokenizer {
+    fn add_filter(&mut self, filter: TokenFilter) {
+        self.filters.push(filter);
+    }
+
+    fn get_filters(&self) -> &Vec<TokenFilter> {
+        &self.filters
+    }
+
+    fn add_char_filter(&mut self, char_filter: CharFilter) {
+        self.char_filters.push(char_filter);
+    }
+
+    fn get_char_filters(&self) -> &Vec<CharFilter> {
+        &self.char_filters
+    }
+
+    fn splits<'a>(&self, input: &'a str) -> Box<Iterator<Item = &'a str> + 'a> {
+        Box::new(iter::once(input).filter(|split| !split.is_empty()))
+    }
+
+    fn is_normalizer(&self) -> bool {
+        true
+    }
+}
+
+impl KeywordTokenizer {
+    pub fn new() -> KeywordTokenizer {
+        KeywordTokenizer {
+            filters: Vec::new(),
+            char_filters: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expectest::prelude::*;
+    use tokenizer::Token;
+
+    #[test]
+    fn single_token() {
+        let mut tokenizer = KeywordTokenizer::new();
+        tokenizer.add_filter(TokenFilter::LowerCase);
+
+        let mut iter = tokenizer.tokenize("In Progress ");
+
+        let next_token = iter.next();
+        expect!(next_token).to(be_some().value(Token {
+            token: String::from("in progress "),
+            position: 1,
+            start_offset: 0,
+            end_offset: 12,
+            position_length: 1,
+            keyword: false,
+        }));
+
+        let next_token = iter.next();
+        expect!(next_token).to(be_none());
+
+        let mut iter = tokenizer.tokenize("");
+        let next_token = iter.next();
+        expect!(next_token).to(be_none());
+    }
+
+    #[test]
+    fn normalize() {
+        let mut tokenizer = KeywordTokenizer::new();
+        tokenizer.add_filter(TokenFilter::LowerCase);
+
+        assert_eq!(tokenizer.normalize("In Progress"), "in progress");
+    }
+}
@@ -0,0 +1,47 @@
+//! Languages supported by the language-specific [`TokenFilter`][filter]s.
+//!
+//! [filter]: ../filter/enum.TokenFilter.html
+
+/// A natural language.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Language {
+    Dutch,
+    English,
+    French,
+    German,
+    Italian,
+    Portuguese,
+    Russian,
+    Spanish,
+}
+
+impl Language {
+    /// Returns all the supported languages.
+    pub fn all() -> &'static [Language] {
+        &[
+            Language::Dutch,
+            Language::English,
+            Language::French,
+            Language::German,
+            Language::Italian,
+            Language::Portuguese,
+            Language::Russian,
+            Language::Spanish,
+        ]
+    }
+
+    /// Returns the lowercased English name of the language, e.g., `"english"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::Dutch => "dutch",
+            Language::English => "english",
+            Language::French => "french",
+            Language::German => "german",
+            Language::Italian => "italian",
+            Language::Portuguese => "portuguese",
+            Language::Russian => "russian",
+            Language::Spanish => "spanish",
+        }
+    }
+}
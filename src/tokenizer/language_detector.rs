@@ -0,0 +1,304 @@
+//! Identify the [`Language`] of a text from its character n-grams.
+//!
+//! Each supported language has a profile of the frequencies of the 1- to 3-grams of its words,
+//! built from a sample text and the language's [stop words][stop_words] embedded in the crate.
+//! A text is attributed to the language whose profile makes its n-grams the most likely.
+//!
+//! The samples are short, so the detection is meant for sentences rather than single words: no
+//! language is detected in a text with too few n-grams, or when its most likely languages are
+//! about as likely as each other.
+//!
+//! Only the eight languages of [`Language`] have a profile, i.e., those with stop words and
+//! stemmers in the crate. A text in another language, e.g., Swedish or Polish, is attributed to
+//! the supported language it is the most likely in, so that such documents need a language tag
+//! of their own.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::tokenizer::language::Language;
+//! use ::iryfful::tokenizer::language_detector::LanguageDetector;
+//!
+//! let detector = LanguageDetector::new();
+//!
+//! let english = detector.detect("The train to London leaves the station at eight.");
+//! assert_eq!(english, Some(Language::English));
+//!
+//! let french = detector.detect("Le train pour Paris part de la gare à huit heures.");
+//! assert_eq!(french, Some(Language::French));
+//!
+//! assert_eq!(detector.detect("42 !"), None);
+//! ```
+//!
+//! [stop_words]: ../filter/stop_words/index.html
+use super::filter::stop_words;
+use super::language::Language;
+use std::collections::HashMap;
+
+/// The maximum length of the n-grams, in characters.
+const MAX_GRAM: usize = 3;
+
+/// The number of distinct n-grams assumed when smoothing the frequencies of a profile.
+const VOCABULARY: f64 = 10_000.0;
+
+/// The minimum number of n-grams of a text to detect its language, i.e., about two short words.
+const MIN_GRAMS: usize = 20;
+
+/// The minimum difference between the log-likelihoods of the two most likely languages of a
+/// text, i.e., the most likely one is at least e² ≈ 7 times as likely as the other.
+const MIN_MARGIN: f64 = 2.0;
+
+/// A language identifier over a set of languages.
+#[derive(Debug)]
+pub struct LanguageDetector {
+    profiles: Vec<Profile>,
+}
+
+impl Default for LanguageDetector {
+    fn default() -> LanguageDetector {
+        LanguageDetector::new()
+    }
+}
+
+impl LanguageDetector {
+    /// Creates a detector over all the supported languages.
+    pub fn new() -> LanguageDetector {
+        LanguageDetector::with_languages(Language::all())
+    }
+
+    /// Creates a detector over the given languages only.
+    pub fn with_languages(languages: &[Language]) -> LanguageDetector {
+        LanguageDetector {
+            profiles: languages.iter().map(|&language| Profile::new(language)).collect(),
+        }
+    }
+
+    /// Returns the most likely language of the given text.
+    ///
+    /// `None` is returned if the text has too few letters, none of its n-grams occurs in any
+    /// profile, or the second most likely language is too close to the most likely one.
+    pub fn detect(&self, text: &str) -> Option<Language> {
+        let grams = grams(text);
+        if grams.len() < MIN_GRAMS {
+            return None;
+        }
+        if !self.profiles
+            .iter()
+            .any(|profile| grams.iter().any(|gram| profile.counts.contains_key(gram)))
+        {
+            return None;
+        }
+        let mut best: Option<(Language, f64)> = None;
+        let mut second = f64::NEG_INFINITY;
+        for profile in self.profiles.iter() {
+            let score = profile.log_likelihood(&grams);
+            match best {
+                Some((_, best_score)) if best_score >= score => second = second.max(score),
+                _ => {
+                    second = best.map_or(second, |(_, best_score)| best_score);
+                    best = Some((profile.language, score));
+                }
+            }
+        }
+        best.and_then(|(language, score)| {
+            if score - second >= MIN_MARGIN {
+                Some(language)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// The n-gram frequencies of a language.
+#[derive(Debug)]
+struct Profile {
+    language: Language,
+    counts: HashMap<String, u32>,
+    total: u32,
+}
+
+impl Profile {
+    fn new(language: Language) -> Profile {
+        let mut counts = HashMap::new();
+        let mut total = 0;
+        let stop_words = stop_words::of(language).join(" ");
+        for gram in grams(sample(language)).into_iter().chain(grams(&stop_words)) {
+            *counts.entry(gram).or_insert(0) += 1;
+            total += 1;
+        }
+        Profile {
+            language,
+            counts,
+            total,
+        }
+    }
+
+    fn log_likelihood(&self, grams: &[String]) -> f64 {
+        let total = f64::from(self.total);
+        grams
+            .iter()
+            .map(|gram| {
+                let count = self.counts.get(gram).cloned().unwrap_or(0);
+                (f64::from(count) / total + 1.0 / VOCABULARY).ln()
+            })
+            .sum()
+    }
+}
+
+/// Returns the 1- to 3-grams of the lowercased words of the text, padded with spaces.
+fn grams(text: &str) -> Vec<String> {
+    let mut grams = Vec::new();
+    for word in text.split(|c: char| !c.is_alphabetic()) {
+        if word.is_empty() {
+            continue;
+        }
+        let chars: Vec<char> = " "
+            .chars()
+            .chain(word.chars().flat_map(char::to_lowercase))
+            .chain(" ".chars())
+            .collect();
+        for n in 1..=MAX_GRAM {
+            for gram in chars.windows(n) {
+                if gram != [' '] {
+                    grams.push(gram.iter().collect());
+                }
+            }
+        }
+    }
+    grams
+}
+
+/// Returns a sample text of the given language: the first articles of the Universal Declaration
+/// of Human Rights, followed by a description of everyday life.
+fn sample(language: Language) -> &'static str {
+    match language {
+        Language::Dutch => {
+            "Alle mensen worden vrij en gelijk in waardigheid en rechten geboren. Zij zijn \
+             begiftigd met verstand en geweten, en behoren zich jegens elkander in een geest van \
+             broederschap te gedragen. Een ieder heeft het recht op leven, vrijheid en \
+             onschendbaarheid van zijn persoon. Niemand zal in slavernij of dienstbaarheid \
+             gehouden worden. \
+             Mijn familie woont in een klein huis bij de rivier. Elke ochtend drinken we koffie en \
+             lezen we de krant voordat we naar het werk gaan. 's Avonds spelen de kinderen in de \
+             tuin terwijl wij het avondeten klaarmaken."
+        }
+        Language::English => {
+            "All human beings are born free and equal in dignity and rights. They are endowed \
+             with reason and conscience and should act towards one another in a spirit of \
+             brotherhood. Everyone has the right to life, liberty and security of person. No one \
+             shall be held in slavery or servitude; slavery and the slave trade shall be \
+             prohibited in all their forms. \
+             My family lives in a small house near the river. Every morning we drink coffee and \
+             read the newspaper before we go to work. In the evening, the children play in the \
+             garden while we cook dinner."
+        }
+        Language::French => {
+            "Tous les êtres humains naissent libres et égaux en dignité et en droits. Ils sont \
+             doués de raison et de conscience et doivent agir les uns envers les autres dans un \
+             esprit de fraternité. Tout individu a droit à la vie, à la liberté et à la sûreté \
+             de sa personne. Nul ne sera tenu en esclavage ni en servitude. \
+             Ma famille habite dans une petite maison près de la rivière. Chaque matin, nous \
+             buvons du café et lisons le journal avant d'aller au travail. Le soir, les enfants \
+             jouent dans le jardin pendant que nous préparons le dîner."
+        }
+        Language::German => {
+            "Alle Menschen sind frei und gleich an Würde und Rechten geboren. Sie sind mit \
+             Vernunft und Gewissen begabt und sollen einander im Geist der Brüderlichkeit \
+             begegnen. Jeder hat das Recht auf Leben, Freiheit und Sicherheit der Person. \
+             Niemand darf in Sklaverei oder Leibeigenschaft gehalten werden. \
+             Meine Familie wohnt in einem kleinen Haus in der Nähe des Flusses. Jeden Morgen \
+             trinken wir Kaffee und lesen die Zeitung, bevor wir zur Arbeit gehen. Am Abend \
+             spielen die Kinder im Garten, während wir das Abendessen vorbereiten."
+        }
+        Language::Italian => {
+            "Tutti gli esseri umani nascono liberi ed eguali in dignità e diritti. Essi sono \
+             dotati di ragione e di coscienza e devono agire gli uni verso gli altri in spirito \
+             di fratellanza. Ogni individuo ha diritto alla vita, alla libertà ed alla sicurezza \
+             della propria persona. Nessun individuo potrà essere tenuto in stato di schiavitù o \
+             di servitù. \
+             La mia famiglia vive in una piccola casa vicino al fiume. Ogni mattina beviamo il \
+             caffè e leggiamo il giornale prima di andare al lavoro. La sera i bambini giocano nel \
+             giardino mentre noi prepariamo la cena."
+        }
+        Language::Portuguese => {
+            "Todos os seres humanos nascem livres e iguais em dignidade e em direitos. Dotados \
+             de razão e de consciência, devem agir uns para com os outros em espírito de \
+             fraternidade. Todo o indivíduo tem direito à vida, à liberdade e à segurança \
+             pessoal. Ninguém será mantido em escravatura ou em servidão. \
+             A minha família vive numa pequena casa perto do rio. Todas as manhãs bebemos café e \
+             lemos o jornal antes de ir para o trabalho. À noite, as crianças brincam no jardim \
+             enquanto preparamos o jantar."
+        }
+        Language::Russian => {
+            "Все люди рождаются свободными и равными в своем достоинстве и правах. Они наделены \
+             разумом и совестью и должны поступать в отношении друг друга в духе братства. \
+             Каждый человек имеет право на жизнь, на свободу и на личную неприкосновенность. \
+             Никто не должен содержаться в рабстве или в подневольном состоянии. \
+             Моя семья живёт в маленьком доме у реки. Каждое утро мы пьём кофе и читаем газету \
+             перед тем, как идти на работу. Вечером дети играют в саду, пока мы готовим ужин."
+        }
+        Language::Spanish => {
+            "Todos los seres humanos nacen libres e iguales en dignidad y derechos y, dotados \
+             como están de razón y conciencia, deben comportarse fraternalmente los unos con los \
+             otros. Todo individuo tiene derecho a la vida, a la libertad y a la seguridad de su \
+             persona. Nadie estará sometido a esclavitud ni a servidumbre. \
+             Mi familia vive en una casa pequeña cerca del río. Cada mañana tomamos café y leemos \
+             el periódico antes de ir al trabajo. Por la tarde, los niños juegan en el jardín \
+             mientras preparamos la cena."
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        let detector = LanguageDetector::new();
+        let texts = [
+            (Language::Dutch, "Het is vandaag erg koud, dus ik blijf thuis met een boek."),
+            (Language::English, "The weather is very cold today, so I will stay at home."),
+            (Language::French, "Il fait très froid aujourd'hui, donc je reste à la maison."),
+            (Language::German, "Heute ist es sehr kalt, deshalb bleibe ich zu Hause."),
+            (Language::Italian, "Oggi fa molto freddo, quindi resto a casa con un libro."),
+            (Language::Portuguese, "Hoje está muito frio, então eu fico em casa com um livro."),
+            (Language::Russian, "Сегодня очень холодно, поэтому я останусь дома."),
+            (Language::Spanish, "Hoy hace mucho frío, así que me quedo en casa con un libro."),
+        ];
+        for &(language, text) in texts.iter() {
+            assert_eq!(detector.detect(text), Some(language), "detecting {:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_undetected() {
+        let detector = LanguageDetector::new();
+        assert_eq!(detector.detect(""), None);
+        assert_eq!(detector.detect("3.14 + 42 = ?"), None);
+        assert_eq!(detector.detect("東京"), None);
+    }
+
+    #[test]
+    fn test_too_little_evidence() {
+        let detector = LanguageDetector::new();
+        // too few n-grams
+        assert_eq!(detector.detect("a"), None);
+        assert_eq!(detector.detect("house"), None);
+        // about as likely in German as in another language
+        assert_eq!(detector.detect("guten Morgen"), None);
+
+        assert_eq!(detector.detect("the cat"), Some(Language::English));
+    }
+
+    #[test]
+    fn test_with_languages() {
+        let detector = LanguageDetector::with_languages(&[Language::English, Language::German]);
+        assert_eq!(
+            detector.detect("The weather is very cold today."),
+            Some(Language::English)
+        );
+        assert_eq!(detector.detect("Сегодня очень холодно"), None);
+    }
+}
@@ -1,19 +1,52 @@
 //! Splits a string into a list of tokens.
 //!
 //! Available tokenizers:
+//! - [`keyword_tokenizer::KeywordTokenizer`]: outputs the whole string as a single split
+//! - [`ngram_tokenizer::NGramTokenizer`]: splits words into n-grams
+//! - [`ngram_tokenizer::EdgeNGramTokenizer`]: splits words into n-grams anchored at their start
+//! - [`path_hierarchy_tokenizer::PathHierarchyTokenizer`]: splits a path into its ancestors
+//! - [`pattern_tokenizer::PatternTokenizer`]: splits with a regular expression
+//! - [`standard_tokenizer::StandardTokenizer`]: splits on Unicode word boundaries
 //! - [`whitespace_tokenizer::WhiteSpaceTokenizer`]: splits on whitespace
+pub mod analysis;
+pub mod analyzer;
+pub mod char_filter;
+pub mod cjk_tokenizer;
+pub mod error;
 pub mod filter;
+pub mod keyword_tokenizer;
+pub mod language;
+pub mod language_detector;
+pub mod ngram_tokenizer;
+pub mod path_hierarchy_tokenizer;
+pub mod pattern_tokenizer;
+pub mod standard_tokenizer;
 pub mod whitespace_tokenizer;
 
+#[cfg(test)]
+mod word_break_test;
+
+use std::borrow::Cow;
+use std::iter;
+use tokenizer::char_filter::CharFilter;
 use tokenizer::filter::Filter;
 
 /// `Token` is a type that holds an owned slice of the input string after being split by the tokenizer.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct Token {
     /// The position of the token in the input string
     pub position: u32,
     /// A split outputted by a tokenizer
     pub token: String,
+    /// The number of positions spanned by the token, e.g., a synonym replacing a phrase of two
+    /// words spans two positions
+    pub position_length: u32,
+    /// The byte offset in the input string at which the split starts
+    pub start_offset: u32,
+    /// The byte offset in the input string at which the split ends, exclusive
+    pub end_offset: u32,
+    /// Whether the token is a keyword, which filters such as stemmers leave untouched
+    pub keyword: bool,
 }
 
 /// An interface for splitting an input string and further applying [filter::Filter]s on each
@@ -23,17 +56,88 @@ pub trait Tokenizer {
     fn get_filters(&self) -> &Vec<filter::TokenFilter>;
 
     /// Returns an [`Iterator`] over the splits outputted by the tokenizer for the given string.
+    ///
+    /// Each split must be a slice of the input, which allows to compute the offsets of the
+    /// [`Token`]s.
     fn splits<'a>(&self, input: &'a str) -> Box<Iterator<Item = &'a str> + 'a>;
 
+    /// Returns an [`Iterator`] over the splits of [`Tokenizer::splits`] along with their position
+    /// increment, i.e., the distance to the position of the previous split.
+    ///
+    /// By default, each split is at the position following the previous one. A tokenizer
+    /// outputting several splits at the same position, e.g., variants of a word, should return an
+    /// increment of `0` for all but the first of those splits.
+    fn splits_with_increments<'a>(
+        &self,
+        input: &'a str,
+    ) -> Box<Iterator<Item = (u32, &'a str)> + 'a> {
+        Box::new(self.splits(input).map(|split| (1, split)))
+    }
+
+    /// Returns a list of [`char_filter::CharFilter`] to be applied on the input string before it
+    /// is split.
+    fn get_char_filters(&self) -> &Vec<CharFilter>;
+
+    /// Adds a [`char_filter::CharFilter`].
+    ///
+    /// The char filters are applied in the order they are added.
+    fn add_char_filter(&mut self, char_filter: CharFilter);
+
     /// Adds a [`filter::TokenFilter`].
     ///
     /// The order of the filters is important for the final resulting [`Token`].
     fn add_filter(&mut self, filter: filter::TokenFilter);
 
+    /// Returns `true` if this tokenizer is a normalizer, i.e., it outputs the whole input as a
+    /// single [`Token`].
+    ///
+    /// The terms looked up by queries against a field mapped with a normalizer are normalized with
+    /// [`Tokenizer::normalize`], so that they match the indexed values.
+    fn is_normalizer(&self) -> bool {
+        false
+    }
+
+    /// Returns the given text processed with the configured lists of [`char_filter::CharFilter`]s
+    /// and [`filter::TokenFilter`]s, without splitting it.
+    ///
+    /// An empty string is returned if the text is removed by a filter.
+    fn normalize(&self, text: &str) -> String {
+        let text = self.get_char_filters()
+            .iter()
+            .fold(Cow::Borrowed(text), |text, char_filter| {
+                Cow::Owned(char_filter.filter(&text).0)
+            });
+        let token = Token {
+            token: text.to_string(),
+            position: 1,
+            start_offset: 0,
+            end_offset: text.len() as u32,
+            position_length: 1,
+            keyword: false,
+        };
+        self.filter(Box::new(iter::once(token)))
+            .next()
+            .map(|token| token.token)
+            .unwrap_or_default()
+    }
+
+    /// Returns the given stream of [`Token`]s processed with the configured list of
+    /// [`filter::TokenFilter`]s.
+    fn filter<'a>(
+        &'a self,
+        tokens: Box<Iterator<Item = Token> + 'a>,
+    ) -> Box<Iterator<Item = Token> + 'a> {
+        self.get_filters()
+            .iter()
+            .fold(tokens, |tokens, filter| filter.apply(tokens))
+    }
+
     /// Returns an [`Iterator`] over the [`Token`]s created from the outputted slices of
-    /// [`Tokenizer::splits`].
+    /// [`Tokenizer::splits_with_increments`].
     ///
-    /// Each token is processed with the configured list of [`filter::TokenFilter`]s.
+    /// The input string is first transformed by the configured list of
+    /// [`char_filter::CharFilter`]s, and the tokens are then processed with the configured list of
+    /// [`filter::TokenFilter`]s. The offsets of the tokens point into the original input string.
     ///
     /// # Examples
     ///
@@ -46,24 +150,87 @@ pub trait Tokenizer {
     ///
     /// let mut iter = tok.tokenize("aaa bbb \n\n\tccc");
     ///
-    /// assert_eq!(iter.next(), Some(Token{ position: 1, token: String::from("aaa") }));
-    /// assert_eq!(iter.next(), Some(Token{ position: 2, token: String::from("bbb") }));
-    /// assert_eq!(iter.next(), Some(Token{ position: 3, token: String::from("ccc") }));
+    /// assert_eq!(iter.next(), Some(Token{ position: 1, token: String::from("aaa"),
+    ///                                    start_offset: 0, end_offset: 3,
+    ///                                    position_length: 1, keyword: false }));
+    /// assert_eq!(iter.next(), Some(Token{ position: 2, token: String::from("bbb"),
+    ///                                    start_offset: 4, end_offset: 7,
+    ///                                    position_length: 1, keyword: false }));
+    /// assert_eq!(iter.next(), Some(Token{ position: 3, token: String::from("ccc"),
+    ///                                    start_offset: 11, end_offset: 14,
+    ///                                    position_length: 1, keyword: false }));
     /// assert_eq!(iter.next(), None);
     /// ```
     fn tokenize<'a>(&'a self, input: &'a str) -> Box<Iterator<Item = Token> + 'a> {
-        // start the position at 1 to ease out of bounds positions
-        let mut pos = 1;
-        Box::new(self.splits(input).map(move |part| {
-            let mut token = Token {
-                token: String::from(part),
-                position: pos,
-            };
-            for filter in self.get_filters().iter() {
-                filter.apply(&mut token);
-            }
-            pos += 1;
-            token
-        }))
+        if self.get_char_filters().is_empty() {
+            return self.filter(split_tokens(self, input));
+        }
+        let (text, corrections) = char_filter(self, input);
+        // the splits of the filtered input do not outlive it, hence the tokens are collected
+        let tokens: Vec<Token> = self.filter(split_tokens(self, &text))
+            .map(|token| correct_offsets(token, &corrections))
+            .collect();
+        Box::new(tokens.into_iter())
     }
+
+    /// Returns the stages of the tokenization of the given input, see [`analysis`].
+    fn analyze(&self, input: &str) -> analysis::Analysis {
+        analysis::analyze(self, input)
+    }
+}
+
+/// Returns the input transformed by the configured list of [`char_filter::CharFilter`]s, with
+/// the corrections of the offsets of each filter.
+///
+/// The tokens are filtered with offsets into the transformed input, where the text of a token is
+/// the text it spans, e.g., so that the parts of a word have their own offsets, and corrected
+/// with [`correct_offsets`] afterwards.
+fn char_filter<T: Tokenizer + ?Sized>(
+    tokenizer: &T,
+    input: &str,
+) -> (String, Vec<char_filter::OffsetCorrection>) {
+    let char_filters = tokenizer.get_char_filters();
+    let mut text = Cow::Borrowed(input);
+    let mut corrections = Vec::with_capacity(char_filters.len());
+    for char_filter in char_filters {
+        let (filtered, correction) = char_filter.filter(&text);
+        text = Cow::Owned(filtered);
+        corrections.push(correction);
+    }
+    (text.into_owned(), corrections)
+}
+
+/// Returns the token with its offsets into the transformed input corrected to point into the
+/// original input.
+fn correct_offsets(mut token: Token, corrections: &[char_filter::OffsetCorrection]) -> Token {
+    for correction in corrections.iter().rev() {
+        token.start_offset = correction.correct_start(token.start_offset);
+        token.end_offset = correction.correct_end(token.end_offset);
+    }
+    token
+}
+
+/// Returns an [`Iterator`] over the [`Token`]s created from the outputted slices of
+/// [`Tokenizer::splits_with_increments`], before they are filtered.
+fn split_tokens<'a, T: Tokenizer + ?Sized>(
+    tokenizer: &T,
+    input: &'a str,
+) -> Box<Iterator<Item = Token> + 'a> {
+    // the first split has an increment of 1, so that positions start at 1 to ease out of
+    // bounds positions
+    let mut pos = 0;
+    let base = input.as_ptr() as usize;
+    let tokens = tokenizer.splits_with_increments(input).map(move |(increment, part)| {
+        pos += increment;
+        let start_offset = (part.as_ptr() as usize - base) as u32;
+        Token {
+            token: String::from(part),
+            position: pos,
+            start_offset,
+            end_offset: start_offset + part.len() as u32,
+            position_length: 1,
+            keyword: false,
+        }
+    });
+    Box::new(tokens)
 }
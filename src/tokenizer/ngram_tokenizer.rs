@@ -0,0 +1,400 @@
+//! [`Tokenizer`]s that split words into n-grams, i.e., substrings of `n` characters.
+//!
+//! A word is a run of characters belonging to the kept [`CharClass`]es. If no class is kept, the
+//! whole input is a single word. Words shorter than the minimum gram length are dropped, but keep
+//! their position, as the stop words removed by [`TokenFilter::StopWords`] do.
+//!
+//! All the n-grams of a word are at the same position, the position of the word, so that a
+//! [`PhraseQuery`][phrase] over n-grams of consecutive words matches as it would over the words.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::tokenizer::Tokenizer;
+//! use ::iryfful::tokenizer::ngram_tokenizer::{CharClass, EdgeNGramTokenizer};
+//!
+//! let mut tokenizer = EdgeNGramTokenizer::new(2, 3);
+//! tokenizer.keep(CharClass::Letter);
+//! tokenizer.keep(CharClass::Digit);
+//!
+//! let grams: Vec<(u32, String)> = tokenizer
+//!     .tokenize("SD500 card")
+//!     .map(|token| (token.position, token.token))
+//!     .collect();
+//!
+//! assert_eq!(grams, vec![
+//!     (1, String::from("SD")), (1, String::from("SD5")),
+//!     (2, String::from("ca")), (2, String::from("car")),
+//! ]);
+//! ```
+//!
+//! [phrase]: ../../search/query/phrase_query/index.html
+use super::Tokenizer;
+use super::char_filter::CharFilter;
+use super::filter::TokenFilter;
+
+/// A class of characters that belong to words.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CharClass {
+    /// Alphabetic characters, e.g., `a` or `é`.
+    Letter,
+    /// Numeric characters, e.g., `7`.
+    Digit,
+    /// Whitespace characters.
+    Whitespace,
+    /// ASCII punctuation characters, e.g., `!` or `-`.
+    Punctuation,
+    /// Any other character, e.g., `$` or `€`.
+    Symbol,
+}
+
+impl CharClass {
+    /// Returns the class of the given character.
+    pub fn of(c: char) -> CharClass {
+        if c.is_alphabetic() {
+            CharClass::Letter
+        } else if c.is_numeric() {
+            CharClass::Digit
+        } else if c.is_whitespace() {
+            CharClass::Whitespace
+        } else if c.is_ascii_punctuation() && !"$+<=>^`|~".contains(c) {
+            CharClass::Punctuation
+        } else {
+            CharClass::Symbol
+        }
+    }
+}
+
+/// Configuration shared by n-gram tokenizers.
+struct NGramConfig {
+    min_gram: usize,
+    max_gram: usize,
+    token_chars: Vec<CharClass>,
+}
+
+impl NGramConfig {
+    fn new(min_gram: usize, max_gram: usize) -> NGramConfig {
+        assert!(
+            min_gram >= 1 && min_gram <= max_gram,
+            "invalid n-gram lengths: min={} max={}",
+            min_gram,
+            max_gram
+        );
+        NGramConfig {
+            min_gram,
+            max_gram,
+            token_chars: Vec::new(),
+        }
+    }
+
+    fn is_token_char(&self, c: char) -> bool {
+        self.token_chars.is_empty() || self.token_chars.contains(&CharClass::of(c))
+    }
+
+    /// Returns the words of the input, as the list of their characters' byte offsets, including
+    /// the offset of the end of the word.
+    fn words(&self, input: &str) -> Vec<Vec<usize>> {
+        let mut words = Vec::new();
+        let mut word: Vec<usize> = Vec::new();
+        for (offset, c) in input.char_indices() {
+            if self.is_token_char(c) {
+                word.push(offset);
+            } else if !word.is_empty() {
+                word.push(offset);
+                words.push(word);
+                word = Vec::new();
+            }
+        }
+        if !word.is_empty() {
+            word.push(input.len());
+            words.push(word);
+        }
+        words
+    }
+
+    /// Returns the n-grams of each word starting at the given character indices.
+    fn grams<'a, F>(&self, input: &'a str, starts: F) -> Vec<(u32, &'a str)>
+    where
+        F: Fn(usize) -> usize,
+    {
+        let mut grams = Vec::new();
+        // the increment of a dropped word is carried over to the next gram
+        let mut increment = 0;
+        for word in self.words(input) {
+            let n_chars = word.len() - 1;
+            increment += 1;
+            for start in 0..starts(n_chars) {
+                for n in self.min_gram..(self.max_gram + 1) {
+                    if start + n > n_chars {
+                        break;
+                    }
+                    grams.push((increment, &input[word[start]..word[start + n]]));
+                    increment = 0;
+                }
+            }
+        }
+        grams
+    }
+}
+
+/// A [`Tokenizer`] outputting all the n-grams of each word.
+pub struct NGramTokenizer {
+    filters: Vec<TokenFilter>,
+    char_filters: Vec<CharFilter>,
+    config: NGramConfig,
+}
+
+impl Tokenizer for NGramTokenizer {
+    fn add_filter(&mut self, filter: TokenFilter) {
+        self.filters.push(filter);
+    }
+
+    fn get_filters(&self) -> &Vec<TokenFilter> {
+        &self.filters
+    }
+
+    fn add_char_filter(&mut self, char_filter: CharFilter) {
+        self.char_filters.push(char_filter);
+    }
+
+    fn get_char_filters(&self) -> &Vec<CharFilter> {
+        &self.char_filters
+    }
+
+    fn splits<'a>(&self, input: &'a str) -> Box<Iterator<Item = &'a str> + 'a> {
+        Box::new(self.splits_with_increments(input).map(|(_, split)| split))
+    }
+
+    fn splits_with_increments<'a>(
+        &self,
+        input: &'a str,
+    ) -> Box<Iterator<Item = (u32, &'a str)> + 'a> {
+        Box::new(self.config.grams(input, |n_chars| n_chars).into_iter())
+    }
+}
+
+impl NGramTokenizer {
+    /// Creates a tokenizer outputting n-grams of `min_gram` up to `max_gram` characters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_gram` is `0` or greater than `max_gram`.
+    pub fn new(min_gram: usize, max_gram: usize) -> NGramTokenizer {
+        NGramTokenizer {
+            filters: Vec::new(),
+            char_filters: Vec::new(),
+            config: NGramConfig::new(min_gram, max_gram),
+        }
+    }
+
+    /// Keeps the characters of the given class in words.
+    pub fn keep(&mut self, class: CharClass) {
+        self.config.token_chars.push(class);
+    }
+}
+
+/// A [`Tokenizer`] outputting the n-grams at the start of each word, e.g., for search-as-you-type.
+pub struct EdgeNGramTokenizer {
+    filters: Vec<TokenFilter>,
+    char_filters: Vec<CharFilter>,
+    config: NGramConfig,
+}
+
+impl Tokenizer for EdgeNGramTokenizer {
+    fn add_filter(&mut self, filter: TokenFilter) {
+        self.filters.push(filter);
+    }
+
+    fn get_filters(&self) -> &Vec<TokenFilter> {
+        &self.filters
+    }
+
+    fn add_char_filter(&mut self, char_filter: CharFilter) {
+        self.char_filters.push(char_filter);
+    }
+
+    fn get_char_filters(&self) -> &Vec<CharFilter> {
+        &self.char_filters
+    }
+
+    fn splits<'a>(&self, input: &'a str) -> Box<Iterator<Item = &'a str> + 'a> {
+        Box::new(self.splits_with_increments(input).map(|(_, split)| split))
+    }
+
+    fn splits_with_increments<'a>(
+        &self,
+        input: &'a str,
+    ) -> Box<Iterator<Item = (u32, &'a str)> + 'a> {
+        Box::new(self.config.grams(input, |_| 1).into_iter())
+    }
+}
+
+impl EdgeNGramTokenizer {
+    /// Creates a tokenizer outputting edge n-grams of `min_gram` up to `max_gram` characters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_gram` is `0` or greater than `max_gram`.
+    pub fn new(min_gram: usize, max_gram: usize) -> EdgeNGramTokenizer {
+        EdgeNGramTokenizer {
+            filters: Vec::new(),
+            char_filters: Vec::new(),
+            config: NGramConfig::new(min_gram, max_gram),
+        }
+    }
+
+    /// Keeps the characters of the given class in words.
+    pub fn keep(&mut self, class: CharClass) {
+        self.config.token_chars.push(class);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use index::Index;
+    use index::document::Document;
+    use search::IndexSearcher;
+    use search::SearchHit;
+    use search::query::Query;
+    use search::query::phrase_query::PhraseQuery;
+    use search::query::term_query::TermQuery;
+    use tokenizer::filter::TokenFilter;
+
+    fn tokens<T: Tokenizer>(tokenizer: &T, input: &str) -> Vec<(u32, String)> {
+        tokenizer
+            .tokenize(input)
+            .map(|token| (token.position, token.token))
+            .collect()
+    }
+
+    #[test]
+    fn test_char_classes() {
+        assert_eq!(CharClass::of('é'), CharClass::Letter);
+        assert_eq!(CharClass::of('7'), CharClass::Digit);
+        assert_eq!(CharClass::of('\t'), CharClass::Whitespace);
+        assert_eq!(CharClass::of('-'), CharClass::Punctuation);
+        assert_eq!(CharClass::of('$'), CharClass::Symbol);
+        assert_eq!(CharClass::of('€'), CharClass::Symbol);
+    }
+
+    #[test]
+    fn test_ngrams() {
+        let tokenizer = NGramTokenizer::new(1, 2);
+
+        assert_eq!(
+            tokens(&tokenizer, "abc"),
+            vec![
+                (1, String::from("a")),
+                (1, String::from("ab")),
+                (1, String::from("b")),
+                (1, String::from("bc")),
+                (1, String::from("c")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ngrams_of_words() {
+        let mut tokenizer = NGramTokenizer::new(2, 3);
+        tokenizer.keep(CharClass::Letter);
+        tokenizer.keep(CharClass::Digit);
+        tokenizer.add_filter(TokenFilter::LowerCase);
+
+        assert_eq!(
+            tokens(&tokenizer, "A-été 9 xyZ"),
+            vec![
+                (2, String::from("ét")),
+                (2, String::from("été")),
+                (2, String::from("té")),
+                (4, String::from("xy")),
+                (4, String::from("xyz")),
+                (4, String::from("yz")),
+            ]
+        );
+
+        let mut iter = tokenizer.tokenize("A-été");
+        let token = iter.next().unwrap();
+        assert_eq!(token.start_offset, 2);
+        assert_eq!(token.end_offset, 5);
+    }
+
+    #[test]
+    fn test_edge_ngrams() {
+        let mut tokenizer = EdgeNGramTokenizer::new(1, 3);
+        tokenizer.keep(CharClass::Letter);
+
+        assert_eq!(
+            tokens(&tokenizer, "rust is great"),
+            vec![
+                (1, String::from("r")),
+                (1, String::from("ru")),
+                (1, String::from("rus")),
+                (2, String::from("i")),
+                (2, String::from("is")),
+                (3, String::from("g")),
+                (3, String::from("gr")),
+                (3, String::from("gre")),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_gram_lengths() {
+        NGramTokenizer::new(3, 2);
+    }
+
+    #[test]
+    fn test_search_ngrams() {
+        let mut tokenizer = NGramTokenizer::new(3, 3);
+        tokenizer.keep(CharClass::Letter);
+        tokenizer.keep(CharClass::Digit);
+        let mut index: Index = Default::default();
+        index.set_mapping(String::from("code"), tokenizer).unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("code", "SD500 card");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("code", "card SD500");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("code", "SD500 for the card");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("code", "SD500 a card");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = &IndexSearcher::new(&index);
+
+        // substring matching
+        let tq = TermQuery::new("code", "500");
+        let mut iter = tq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), Some(SearchHit::new(1)));
+        assert_eq!(iter.next(), Some(SearchHit::new(2)));
+        assert_eq!(iter.next(), Some(SearchHit::new(3)));
+        assert_eq!(iter.next(), None);
+
+        // grams of consecutive words are at consecutive positions
+        let pq = PhraseQuery::new("code", vec!["D50", "ard"]);
+        let mut iter = pq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), Some(SearchHit::new(1)));
+        assert_eq!(iter.next(), None);
+
+        // a word too short for grams still takes a position
+        let mut pq = PhraseQuery::new("code", vec!["D50", "ard"]);
+        pq.set_slop(2);
+        let mut iter = pq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), Some(SearchHit::new(1)));
+        assert_eq!(iter.next(), Some(SearchHit::new(3)));
+        assert_eq!(iter.next(), None);
+    }
+}
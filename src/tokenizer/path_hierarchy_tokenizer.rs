@@ -0,0 +1,196 @@
+//! A [`Tokenizer`] that splits a path into its ancestors, e.g., for searching the files under a
+//! directory or the products of a category.
+//!
+//! Every prefix of the path ending before a delimiter is output, then the whole path, all at the
+//! same position. In reverse mode, the suffixes starting after a delimiter are output instead,
+//! which suits domain names.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::tokenizer::Tokenizer;
+//! use ::iryfful::tokenizer::path_hierarchy_tokenizer::PathHierarchyTokenizer;
+//!
+//! let tokenizer = PathHierarchyTokenizer::new('/');
+//! let paths: Vec<String> = tokenizer
+//!     .tokenize("/usr/lib/rust")
+//!     .map(|token| token.token)
+//!     .collect();
+//! assert_eq!(paths, vec!["/usr", "/usr/lib", "/usr/lib/rust"]);
+//!
+//! let tokenizer = PathHierarchyTokenizer::reverse('.');
+//! let domains: Vec<String> = tokenizer
+//!     .tokenize("www.example.com")
+//!     .map(|token| token.token)
+//!     .collect();
+//! assert_eq!(domains, vec!["com", "example.com", "www.example.com"]);
+//! ```
+use super::Tokenizer;
+use super::char_filter::CharFilter;
+use super::filter::TokenFilter;
+
+pub struct PathHierarchyTokenizer {
+    filters: Vec<TokenFilter>,
+    char_filters: Vec<CharFilter>,
+    delimiter: char,
+    reverse: bool,
+}
+
+impl Tokenizer for PathHierarchyTokenizer {
+    fn add_filter(&mut self, filter: TokenFilter) {
+        self.filters.push(filter);
+    }
+
+    fn get_filters(&self) -> &Vec<TokenFilter> {
+        &self.filters
+    }
+
+    fn add_char_filter(&mut self, char_filter: CharFilter) {
+        self.char_filters.push(char_filter);
+    }
+
+    fn get_char_filters(&self) -> &Vec<CharFilter> {
+        &self.char_filters
+    }
+
+    fn splits<'a>(&self, input: &'a str) -> Box<Iterator<Item = &'a str> + 'a> {
+        let delimiter = self.delimiter;
+        let mut splits: Vec<&'a str> = if self.reverse {
+            input
+                .rmatch_indices(delimiter)
+                .map(|(offset, _)| &input[offset + delimiter.len_utf8()..])
+                .collect()
+        } else {
+            input
+                .match_indices(delimiter)
+                .map(|(offset, _)| &input[..offset])
+                .collect()
+        };
+        splits.push(input);
+        splits.retain(|split| !split.is_empty());
+        splits.dedup();
+        Box::new(splits.into_iter())
+    }
+
+    fn splits_with_increments<'a>(
+        &self,
+        input: &'a str,
+    ) -> Box<Iterator<Item = (u32, &'a str)> + 'a> {
+        Box::new(
+            self.splits(input)
+                .enumerate()
+                .map(|(i, split)| (if i == 0 { 1 } else { 0 }, split)),
+        )
+    }
+}
+
+impl PathHierarchyTokenizer {
+    /// Creates a tokenizer outputting the ancestors of paths separated by the given delimiter.
+    pub fn new(delimiter: char) -> PathHierarchyTokenizer {
+        PathHierarchyTokenizer {
+            filters: Vec::new(),
+            char_filters: Vec::new(),
+            delimiter,
+            reverse: false,
+        }
+    }
+
+    /// Creates a tokenizer outputting the suffixes of paths separated by the given delimiter,
+    /// e.g., the parent domains of a domain name.
+    pub fn reverse(delimiter: char) -> PathHierarchyTokenizer {
+        PathHierarchyTokenizer {
+            filters: Vec::new(),
+            char_filters: Vec::new(),
+            delimiter,
+            reverse: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use index::Index;
+    use index::document::Document;
+    use search::IndexSearcher;
+    use search::SearchHit;
+    use search::query::Query;
+    use search::query::term_query::TermQuery;
+
+    fn tokens(tokenizer: &PathHierarchyTokenizer, input: &str) -> Vec<(u32, String, u32, u32)> {
+        tokenizer
+            .tokenize(input)
+            .map(|token| {
+                (
+                    token.position,
+                    token.token,
+                    token.start_offset,
+                    token.end_offset,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ancestors() {
+        let tokenizer = PathHierarchyTokenizer::new('/');
+
+        assert_eq!(
+            tokens(&tokenizer, "/usr/lib/libstd.so"),
+            vec![
+                (1, String::from("/usr"), 0, 4),
+                (1, String::from("/usr/lib"), 0, 8),
+                (1, String::from("/usr/lib/libstd.so"), 0, 18),
+            ]
+        );
+        assert_eq!(
+            tokens(&tokenizer, "books"),
+            vec![(1, String::from("books"), 0, 5)]
+        );
+        assert_eq!(tokens(&tokenizer, "/"), vec![(1, String::from("/"), 0, 1)]);
+        assert_eq!(tokens(&tokenizer, ""), vec![]);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let tokenizer = PathHierarchyTokenizer::reverse('.');
+
+        assert_eq!(
+            tokens(&tokenizer, "www.example.com"),
+            vec![
+                (1, String::from("com"), 12, 15),
+                (1, String::from("example.com"), 4, 15),
+                (1, String::from("www.example.com"), 0, 15),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_ancestors() {
+        let mut index: Index = Default::default();
+        index
+            .set_mapping(String::from("path"), PathHierarchyTokenizer::new('/'))
+            .unwrap();
+
+        let mut doc: Document = Default::default();
+        doc.add_field("path", "/usr/lib/rust/libstd.so");
+        index.add_doc(&doc).unwrap();
+
+        doc.clear();
+        doc.add_field("path", "/usr/libexec/rust");
+        index.add_doc(&doc).unwrap();
+
+        let index_search = &IndexSearcher::new(&index);
+
+        let tq = TermQuery::new("path", "/usr/lib");
+        let mut iter = tq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), None);
+
+        let tq = TermQuery::new("path", "/usr");
+        let mut iter = tq.execute(index_search);
+        assert_eq!(iter.next(), Some(SearchHit::new(0)));
+        assert_eq!(iter.next(), Some(SearchHit::new(1)));
+        assert_eq!(iter.next(), None);
+    }
+}
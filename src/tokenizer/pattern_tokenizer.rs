@@ -0,0 +1,188 @@
+//! A [`Tokenizer`] that splits a string with a regular expression.
+//!
+//! The regular expression is either used as the separator of splits, or describes the splits
+//! themselves, either as a whole match or as one of its capture groups. Empty splits are dropped.
+//!
+//! # Examples
+//!
+//! ```
+//! use ::iryfful::index::Index;
+//! use ::iryfful::tokenizer::pattern_tokenizer::PatternTokenizer;
+//!
+//! let mut index: Index = Default::default();
+//!
+//! // split identifiers such as "org.iryfful.Index" on dots
+//! index.set_mapping(String::from("class"), PatternTokenizer::split(r"\.").unwrap())
+//!     .unwrap();
+//! // index the value of key=value pairs in log lines
+//! index.set_mapping(String::from("log"), PatternTokenizer::group(r"\w+=(\S+)", 1).unwrap())
+//!     .unwrap();
+//! ```
+use super::Tokenizer;
+use super::char_filter::CharFilter;
+use super::error::TokenizerError;
+use super::filter::TokenFilter;
+use regex::Regex;
+
+type TokenizerResult<T> = Result<T, TokenizerError>;
+
+/// How the regular expression outputs splits.
+enum PatternMode {
+    /// The regular expression matches the separators of splits.
+    Split,
+    /// The regular expression matches the splits.
+    Matches,
+    /// A capture group of the regular expression matches the splits.
+    Group(usize),
+}
+
+pub struct PatternTokenizer {
+    filters: Vec<TokenFilter>,
+    char_filters: Vec<CharFilter>,
+    regex: Regex,
+    mode: PatternMode,
+}
+
+impl Tokenizer for PatternTokenizer {
+    fn add_filter(&mut self, filter: TokenFilter) {
+        self.filters.push(filter);
+    }
+
+    fn get_filters(&self) -> &Vec<TokenFilter> {
+        &self.filters
+    }
+
+    fn add_char_filter(&mut self, char_filter: CharFilter) {
+        self.char_filters.push(char_filter);
+    }
+
+    fn get_char_filters(&self) -> &Vec<CharFilter> {
+        &self.char_filters
+    }
+
+    fn splits<'a>(&self, input: &'a str) -> Box<Iterator<Item = &'a str> + 'a> {
+        let splits: Vec<&'a str> = match self.mode {
+            PatternMode::Split => self.regex.split(input).collect(),
+            PatternMode::Matches => self.regex.find_iter(input).map(|m| m.as_str()).collect(),
+            PatternMode::Group(group) => self.regex
+                .captures_iter(input)
+                .filter_map(|captures| captures.get(group))
+                .map(|m| m.as_str())
+                .collect(),
+        };
+        Box::new(splits.into_iter().filter(|split| !split.is_empty()))
+    }
+}
+
+impl PatternTokenizer {
+    /// Creates a tokenizer splitting the input on the matches of the regular expression.
+    ///
+    /// # Errors
+    ///
+    /// An [`TokenizerError::InvalidPattern`] error is returned if the pattern is not a valid
+    /// regular expression.
+    pub fn split(pattern: &str) -> TokenizerResult<PatternTokenizer> {
+        PatternTokenizer::new(pattern, PatternMode::Split)
+    }
+
+    /// Creates a tokenizer outputting the matches of the regular expression.
+    ///
+    /// # Errors
+    ///
+    /// See [`PatternTokenizer::split`].
+    pub fn matches(pattern: &str) -> TokenizerResult<PatternTokenizer> {
+        PatternTokenizer::new(pattern, PatternMode::Matches)
+    }
+
+    /// Creates a tokenizer outputting the given capture group of the matches of the regular
+    /// expression. The group `0` is the whole match.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of [`PatternTokenizer::split`], an
+    /// [`TokenizerError::MissingCaptureGroup`] error is returned if the regular expression has no
+    /// such capture group.
+    pub fn group(pattern: &str, group: usize) -> TokenizerResult<PatternTokenizer> {
+        let tokenizer = PatternTokenizer::new(pattern, PatternMode::Group(group))?;
+        if group >= tokenizer.regex.captures_len() {
+            return Err(TokenizerError::MissingCaptureGroup {
+                pattern: pattern.to_string(),
+                group,
+            });
+        }
+        Ok(tokenizer)
+    }
+
+    fn new(pattern: &str, mode: PatternMode) -> TokenizerResult<PatternTokenizer> {
+        let regex = Regex::new(pattern).map_err(|err| TokenizerError::InvalidPattern {
+            pattern: pattern.to_string(),
+            reason: err.to_string(),
+        })?;
+        Ok(PatternTokenizer {
+            filters: Vec::new(),
+            char_filters: Vec::new(),
+            regex,
+            mode,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn splits<'a>(tokenizer: &PatternTokenizer, input: &'a str) -> Vec<&'a str> {
+        tokenizer.splits(input).collect()
+    }
+
+    #[test]
+    fn test_split() {
+        let tokenizer = PatternTokenizer::split(r"[.\s]+").unwrap();
+
+        assert_eq!(
+            splits(&tokenizer, " org.iryfful.Index  main. "),
+            vec!["org", "iryfful", "Index", "main"]
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        let tokenizer = PatternTokenizer::matches(r"\d+(\.\d+)*").unwrap();
+
+        assert_eq!(
+            splits(&tokenizer, "rust 1.24.1 on linux 4.15"),
+            vec!["1.24.1", "4.15"]
+        );
+    }
+
+    #[test]
+    fn test_group() {
+        let mut tokenizer = PatternTokenizer::group(r"(\w+)=(\S*)", 2).unwrap();
+        tokenizer.add_filter(TokenFilter::LowerCase);
+
+        let tokens: Vec<(u32, String, u32)> = tokenizer
+            .tokenize("level=WARN code= msg=timeout")
+            .map(|token| (token.position, token.token, token.start_offset))
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                (1, String::from("warn"), 6),
+                (2, String::from("timeout"), 21),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        match PatternTokenizer::split("a(b") {
+            Err(TokenizerError::InvalidPattern { pattern, .. }) => assert_eq!(pattern, "a(b"),
+            _ => panic!("the pattern should be invalid"),
+        }
+        match PatternTokenizer::group(r"(\w+)=(\S*)", 3) {
+            Err(TokenizerError::MissingCaptureGroup { group, .. }) => assert_eq!(group, 3),
+            _ => panic!("the capture group should be missing"),
+        }
+    }
+}
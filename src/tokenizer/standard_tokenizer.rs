@@ -0,0 +1,116 @@
+//! A [`Tokenizer`] that splits a string on word boundaries, as defined by the Unicode Standard
+//! Annex #29.
+//!
+//! Segments without any alphanumeric character, such as whitespace and punctuation, are dropped.
+//! Following the Unicode rules:
+//! - apostrophes and decimal separators are kept within a word, e.g., `can't` or `3.14`;
+//! - hyphenated words are split, e.g., `e-mail` gives `e` and `mail` at consecutive positions.
+use super::Tokenizer;
+use super::char_filter::CharFilter;
+use super::filter::TokenFilter;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub struct StandardTokenizer {
+    filters: Vec<TokenFilter>,
+    char_filters: Vec<CharFilter>,
+}
+
+impl Tokenizer for StandardTokenizer {
+    fn add_filter(&mut self, filter: TokenFilter) {
+        self.filters.push(filter);
+    }
+
+    fn get_filters(&self) -> &Vec<TokenFilter> {
+        &self.filters
+    }
+
+    fn add_char_filter(&mut self, char_filter: CharFilter) {
+        self.char_filters.push(char_filter);
+    }
+
+    fn get_char_filters(&self) -> &Vec<CharFilter> {
+        &self.char_filters
+    }
+
+    fn splits<'a>(&self, input: &'a str) -> Box<Iterator<Item = &'a str> + 'a> {
+        Box::new(
+            input
+                .split_word_bounds()
+                .filter(|segment| segment.chars().any(char::is_alphanumeric)),
+        )
+    }
+}
+
+impl StandardTokenizer {
+    pub fn new() -> StandardTokenizer {
+        StandardTokenizer {
+            filters: Vec::new(),
+            char_filters: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokenizer::word_break_test::TEST_WORD;
+
+    fn splits(input: &str) -> Vec<&str> {
+        StandardTokenizer::new().splits(input).collect()
+    }
+
+    #[test]
+    fn drops_punctuation() {
+        assert_eq!(
+            splits("Rust, the language; (really) -- \"fast\"!"),
+            vec!["Rust", "the", "language", "really", "fast"]
+        );
+        assert_eq!(splits(" ... ,;: !? "), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn keeps_words_sensible() {
+        assert_eq!(
+            splits("can't send an e-mail for 3.14 or 1,000.5 US$"),
+            vec!["can't", "send", "an", "e", "mail", "for", "3.14", "or", "1,000.5", "US"]
+        );
+        assert_eq!(splits("l'été über naïve"), vec!["l'été", "über", "naïve"]);
+    }
+
+    #[test]
+    fn positions_and_offsets() {
+        let tokenizer = StandardTokenizer::new();
+
+        let tokens: Vec<(u32, String, u32, u32)> = tokenizer
+            .tokenize("rust, rust!")
+            .map(|token| {
+                (
+                    token.position,
+                    token.token,
+                    token.start_offset,
+                    token.end_offset,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                (1, String::from("rust"), 0, 4),
+                (2, String::from("rust"), 6, 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn unicode_word_break_test_data() {
+        for &(input, segments) in TEST_WORD.iter() {
+            let expected: Vec<&str> = segments
+                .iter()
+                .cloned()
+                .filter(|segment| segment.chars().any(char::is_alphanumeric))
+                .collect();
+            assert_eq!(splits(input), expected, "splitting {:?}", input);
+        }
+    }
+}
@@ -1,9 +1,11 @@
 //! A [`Tokenizer`] that splits a string using [`String::split_whitespace`].
 use super::Tokenizer;
+use super::char_filter::CharFilter;
 use super::filter::TokenFilter;
 
 pub struct WhiteSpaceTokenizer {
     filters: Vec<TokenFilter>,
+    char_filters: Vec<CharFilter>,
 }
 
 impl Tokenizer for WhiteSpaceTokenizer {
@@ -15,6 +17,14 @@ impl Tokenizer for WhiteSpaceTokenizer {
         &self.filters
     }
 
+    fn add_char_filter(&mut self, char_filter: CharFilter) {
+        self.char_filters.push(char_filter);
+    }
+
+    fn get_char_filters(&self) -> &Vec<CharFilter> {
+        &self.char_filters
+    }
+
     fn splits<'a>(&self, input: &'a str) -> Box<Iterator<Item = &'a str> + 'a> {
         Box::new(input.split_whitespace())
     }
@@ -24,6 +34,7 @@ impl WhiteSpaceTokenizer {
     pub fn new() -> WhiteSpaceTokenizer {
         WhiteSpaceTokenizer {
             filters: Vec::new(),
+            char_filters: Vec::new(),
         }
     }
 }
@@ -44,24 +55,57 @@ mod tests {
         expect!(next_token).to(be_some().value(Token {
             token: String::from("aaa"),
             position: 1,
+            start_offset: 1,
+            end_offset: 4,
+            position_length: 1,
+            keyword: false,
         }));
 
         let next_token = iter.next();
         expect!(next_token).to(be_some().value(Token {
             token: String::from("bbb"),
             position: 2,
+            start_offset: 5,
+            end_offset: 8,
+            position_length: 1,
+            keyword: false,
         }));
 
         let next_token = iter.next();
         expect!(next_token).to(be_some().value(Token {
             token: String::from("ccc"),
             position: 3,
+            start_offset: 11,
+            end_offset: 14,
+            position_length: 1,
+            keyword: false,
         }));
 
         let next_token = iter.next();
         expect!(next_token).to(be_none());
     }
 
+    #[test]
+    fn offsets_of_multi_byte_characters() {
+        let white_space_tokenizer = WhiteSpaceTokenizer::new();
+
+        let input = "été\u{3000}naïve";
+        let mut iter = white_space_tokenizer.tokenize(input);
+
+        let next_token = iter.next().unwrap();
+        assert_eq!(next_token.start_offset, 0);
+        assert_eq!(next_token.end_offset, 5);
+
+        let next_token = iter.next().unwrap();
+        assert_eq!(
+            &input[next_token.start_offset as usize..next_token.end_offset as usize],
+            "naïve"
+        );
+
+        let next_token = iter.next();
+        expect!(next_token).to(be_none());
+    }
+
     #[test]
     fn reuse_tokenizer() {
         let white_space_tokenizer = WhiteSpaceTokenizer::new();
@@ -72,12 +116,20 @@ mod tests {
         expect!(next_token).to(be_some().value(Token {
             token: String::from("aaa"),
             position: 1,
+            start_offset: 0,
+            end_offset: 3,
+            position_length: 1,
+            keyword: false,
         }));
 
         let next_token = iter.next();
         expect!(next_token).to(be_some().value(Token {
             token: String::from("bbb"),
             position: 2,
+            start_offset: 4,
+            end_offset: 7,
+            position_length: 1,
+            keyword: false,
         }));
 
         let next_token = iter.next();
@@ -89,12 +141,20 @@ mod tests {
         expect!(next_token).to(be_some().value(Token {
             token: String::from("ccc"),
             position: 1,
+            start_offset: 0,
+            end_offset: 3,
+            position_length: 1,
+            keyword: false,
         }));
 
         let next_token = iter.next();
         expect!(next_token).to(be_some().value(Token {
             token: String::from("ddd"),
             position: 2,
+            start_offset: 4,
+            end_offset: 7,
+            position_length: 1,
+            keyword: false,
         }));
 
         let next_token = iter.next();
@@ -113,18 +173,30 @@ mod tests {
         expect!(next_token).to(be_some().value(Token {
             token: String::from("aaa"),
             position: 1,
+            start_offset: 0,
+            end_offset: 3,
+            position_length: 1,
+            keyword: false,
         }));
 
         let next_token = iter.next();
         expect!(next_token).to(be_some().value(Token {
             token: String::from("bbb"),
             position: 2,
+            start_offset: 4,
+            end_offset: 7,
+            position_length: 1,
+            keyword: false,
         }));
 
         let next_token = iter.next();
         expect!(next_token).to(be_some().value(Token {
             token: String::from("ccc"),
             position: 3,
+            start_offset: 8,
+            end_offset: 11,
+            position_length: 1,
+            keyword: false,
         }));
 
         let next_token = iter.next();